@@ -0,0 +1,181 @@
+use common::{input_lines, lines_from_str, InputLine, ParseError};
+use std::{collections::BTreeMap, fs, path::Path};
+
+pub const DEFAULT_ANSWERS_FILENAME: &str = "answers.toml";
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ExpectedAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+pub fn read_answers_from_file(
+    filename: impl AsRef<Path>,
+) -> Result<BTreeMap<u32, ExpectedAnswers>, ParseError> {
+    let filename = filename.as_ref();
+    let input = fs::read_to_string(filename).map_err(|e| ParseError::from_io(filename, e))?;
+    parse_answers(&input).map_err(|e| e.with_file(filename))
+}
+
+// A small subset of TOML: `[dayN]` tables holding `part1`/`part2` keys whose values are
+// integers, "basic strings" or """multi-line strings""" (for answers drawn as ASCII art).
+pub fn parse_answers(input: &str) -> Result<BTreeMap<u32, ExpectedAnswers>, ParseError> {
+    let lines_from_str = lines_from_str(input);
+    let lines = input_lines(&lines_from_str).collect::<Vec<InputLine>>();
+    let mut answers = BTreeMap::new();
+    let mut day = None;
+
+    let mut index = 0;
+    while index < lines.len() {
+        let line = &lines[index];
+        index += 1;
+        let text = line.text.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+
+        if let Some(table) = text.strip_prefix('[') {
+            let table = match table.strip_suffix(']') {
+                Some(table) => table.trim(),
+                None => return Err(line.error_at_end("`]`")),
+            };
+            let number = match table.strip_prefix("day") {
+                Some(number) => line.parse_number::<u32>(number, "a day number")?,
+                None => return Err(line.error_at(table, "a table like [day1]")),
+            };
+            if answers.insert(number, ExpectedAnswers::default()).is_some() {
+                return Err(line.error_at(table, "each day only once"));
+            }
+            day = Some(number);
+            continue;
+        }
+
+        let (key, value) = match text.split_once('=') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => return Err(line.error_at_end("`key = value`")),
+        };
+        let expected = match day.and_then(|day| answers.get_mut(&day)) {
+            Some(expected) => expected,
+            None => return Err(line.error_at(key, "a [dayN] table before any key")),
+        };
+        let part = match key {
+            "part1" => &mut expected.part1,
+            "part2" => &mut expected.part2,
+            _ => return Err(line.error_at(key, "part1 or part2")),
+        };
+        if part.is_some() {
+            return Err(line.error_at(key, "each part only once"));
+        }
+
+        *part = Some(if let Some(rest) = value.strip_prefix("\"\"\"") {
+            parse_multi_line_string(line, rest, &lines, &mut index)?
+        } else if let Some(rest) = value.strip_prefix('"') {
+            match rest.strip_suffix('"') {
+                Some(content) => unescape(line, content)?,
+                None => return Err(line.error_at_end("a closing `\"`")),
+            }
+        } else {
+            line.parse_number::<i64>(value, "a number or a quoted string")?
+                .to_string()
+        });
+    }
+
+    Ok(answers)
+}
+
+fn parse_multi_line_string(
+    line: &InputLine,
+    rest: &str,
+    lines: &[InputLine],
+    index: &mut usize,
+) -> Result<String, ParseError> {
+    if let Some((content, _)) = rest.split_once("\"\"\"") {
+        return unescape(line, content);
+    }
+
+    // a newline right after the opening quotes is not part of the string
+    let mut content = rest.to_string();
+    let mut first = rest.is_empty();
+    while let Some(next) = lines.get(*index) {
+        *index += 1;
+        if !first {
+            content.push('\n');
+        }
+        first = false;
+        match next.text.split_once("\"\"\"") {
+            Some((last, _)) => {
+                content.push_str(last);
+                return unescape(next, &content);
+            }
+            None => content.push_str(next.text),
+        }
+    }
+
+    Err(line.error_at_end("a closing `\"\"\"`"))
+}
+
+fn unescape(line: &InputLine, content: &str) -> Result<String, ParseError> {
+    let mut unescaped = String::new();
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('"') => unescaped.push('"'),
+            Some('\\') => unescaped.push('\\'),
+            _ => return Err(line.error_at(line.text.trim(), "one of \\n \\t \\\" \\\\")),
+        }
+    }
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_expected_answers_given_numbers_strings_and_multi_line_strings() {
+        let input = "\
+# real input answers
+[day1]
+part1 = 1722
+part2 = \"1748\"
+
+[day13]
+part1 = \"607\"
+part2 = \"\"\"
+
+#..#
+####
+\"\"\"
+";
+        let answers = parse_answers(input).unwrap();
+
+        assert_eq!(answers[&1].part1.as_deref(), Some("1722"));
+        assert_eq!(answers[&1].part2.as_deref(), Some("1748"));
+        assert_eq!(answers[&13].part2.as_deref(), Some("\n#..#\n####\n"));
+    }
+
+    #[test]
+    fn should_get_parse_error_given_an_unknown_key_or_a_key_outside_a_day() {
+        let error = parse_answers("[day1]\npart3 = 1").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.expected, "part1 or part2");
+
+        let error = parse_answers("part1 = 1").unwrap_err();
+        assert_eq!(error.line, 1);
+        assert_eq!(error.expected, "a [dayN] table before any key");
+    }
+
+    #[test]
+    fn should_parse_the_answers_file_at_the_workspace_root() {
+        let answers = read_answers_from_file("../answers.toml").unwrap();
+
+        assert_eq!(answers[&12].part2.as_deref(), Some("96988"));
+        assert!(answers[&13].part2.as_deref().unwrap().starts_with("\n.##."));
+    }
+}
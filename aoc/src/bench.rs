@@ -0,0 +1,204 @@
+use crate::days::{get_input_filename, solve_day_from_str};
+use common::ParseError;
+use std::{error::Error, fmt, fs, time::Duration};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+    pub json: bool,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 1,
+            iterations: 10,
+            json: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhaseStats {
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayBench {
+    pub day: u32,
+    pub parse: PhaseStats,
+    pub part1: PhaseStats,
+    pub part2: PhaseStats,
+}
+
+pub fn parse_bench_options(args: &[&str]) -> Result<BenchOptions, String> {
+    let mut options = BenchOptions::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match *arg {
+            "--json" => options.json = true,
+            "--warmup" | "--iterations" => {
+                let count = match args.next().map(|count| count.parse::<usize>()) {
+                    Some(Ok(count)) => count,
+                    _ => return Err(format!("{} needs a number", arg)),
+                };
+                if *arg == "--warmup" {
+                    options.warmup = count;
+                } else if count == 0 {
+                    return Err("--iterations needs at least 1".to_string());
+                } else {
+                    options.iterations = count;
+                }
+            }
+            _ => return Err(format!("unknown bench option {}", arg)),
+        }
+    }
+    Ok(options)
+}
+
+// Nearest-rank percentile over the sorted samples, so p95 of 10 runs is the slowest one.
+pub fn get_phase_stats(mut samples: Vec<Duration>) -> PhaseStats {
+    samples.sort_unstable();
+    let percentile = |p: usize| {
+        let rank = (samples.len() * p).div_ceil(100).max(1);
+        samples.get(rank - 1).copied().unwrap_or_default()
+    };
+    PhaseStats {
+        median: percentile(50),
+        p95: percentile(95),
+    }
+}
+
+pub fn bench_day(day: u32, options: &BenchOptions) -> Result<DayBench, Box<dyn Error>> {
+    let filename = get_input_filename(day);
+    let input = fs::read_to_string(&filename).map_err(|e| ParseError::from_io(&filename, e))?;
+
+    for _ in 0..options.warmup {
+        solve_day_from_str(day, &input)?;
+    }
+
+    let mut parse_times = vec![];
+    let mut part1_times = vec![];
+    let mut part2_times = vec![];
+    for _ in 0..options.iterations {
+        let answers = solve_day_from_str(day, &input)?;
+        parse_times.push(answers.parse_time);
+        part1_times.push(answers.part1_time);
+        part2_times.push(answers.part2_time);
+    }
+
+    Ok(DayBench {
+        day,
+        parse: get_phase_stats(parse_times),
+        part1: get_phase_stats(part1_times),
+        part2: get_phase_stats(part2_times),
+    })
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+impl fmt::Display for DayBench {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (phase, stats) in [
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ] {
+            writeln!(
+                f,
+                "day {:>2} {:<6}  median {:>10.3}ms  p95 {:>10.3}ms",
+                self.day,
+                phase,
+                millis(stats.median),
+                millis(stats.p95)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub fn get_bench_json(benches: &[DayBench], options: &BenchOptions) -> String {
+    let phase_json = |stats: PhaseStats| {
+        format!(
+            "{{\"median_ns\": {}, \"p95_ns\": {}}}",
+            stats.median.as_nanos(),
+            stats.p95.as_nanos()
+        )
+    };
+    let days = benches
+        .iter()
+        .map(|bench| {
+            format!(
+                "    {{\"day\": {}, \"parse\": {}, \"part1\": {}, \"part2\": {}}}",
+                bench.day,
+                phase_json(bench.parse),
+                phase_json(bench.part1),
+                phase_json(bench.part2)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(",\n");
+    format!(
+        "{{\n  \"warmup\": {},\n  \"iterations\": {},\n  \"days\": [\n{}\n  ]\n}}",
+        options.warmup, options.iterations, days
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_median_and_p95_given_the_samples() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = get_phase_stats(samples);
+
+        assert_eq!(stats.median, Duration::from_millis(10));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+
+        let stats = get_phase_stats(vec![Duration::from_millis(3)]);
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn should_get_bench_options_given_the_arguments() {
+        let options = parse_bench_options(&["--warmup", "0", "--iterations", "5", "--json"]);
+        assert_eq!(
+            options.unwrap(),
+            BenchOptions {
+                warmup: 0,
+                iterations: 5,
+                json: true,
+            }
+        );
+
+        assert!(parse_bench_options(&["--iterations", "0"]).is_err());
+        assert!(parse_bench_options(&["--warmup"]).is_err());
+        assert!(parse_bench_options(&["--fast"]).is_err());
+    }
+
+    #[test]
+    fn should_get_json_with_nanoseconds_for_every_phase() {
+        let stats = PhaseStats {
+            median: Duration::from_micros(1),
+            p95: Duration::from_micros(2),
+        };
+        let bench = DayBench {
+            day: 7,
+            parse: stats,
+            part1: stats,
+            part2: stats,
+        };
+        let json = get_bench_json(&[bench], &BenchOptions::default());
+
+        assert!(json.contains("\"iterations\": 10"));
+        assert!(json.contains(
+            "{\"day\": 7, \"parse\": {\"median_ns\": 1000, \"p95_ns\": 2000}, \"part1\": "
+        ));
+    }
+}
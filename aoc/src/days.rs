@@ -0,0 +1,161 @@
+use common::{ParseError, Solution, SolveError};
+use std::{
+    error::Error,
+    fs,
+    io::BufRead,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+pub const IMPLEMENTED_DAYS: [u32; 14] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+
+#[derive(Debug, Clone)]
+pub struct DayAnswers {
+    pub part1: String,
+    pub part2: String,
+    pub parse_time: Duration,
+    pub part1_time: Duration,
+    pub part2_time: Duration,
+}
+
+pub fn get_input_filename(day: u32) -> String {
+    format!("day{}/day{}_input.txt", day, day)
+}
+
+pub fn solve_day(day: u32, filename: impl AsRef<Path>) -> Result<DayAnswers, Box<dyn Error>> {
+    let filename = filename.as_ref();
+    let input = fs::read_to_string(filename).map_err(|e| ParseError::from_io(filename, e))?;
+    solve_day_from_str(day, &input).map_err(|e| match e.downcast::<ParseError>() {
+        Ok(parse_error) => parse_error.with_file(filename).into(),
+        Err(e) => e,
+    })
+}
+
+pub fn solve_day_from_reader(
+    day: u32,
+    mut reader: impl BufRead,
+) -> Result<DayAnswers, Box<dyn Error>> {
+    let mut input = String::new();
+    reader
+        .read_to_string(&mut input)
+        .map_err(|e| ParseError::from_io("<input>", e))?;
+    solve_day_from_str(day, &input)
+}
+
+pub fn solve_day_from_str(day: u32, input: &str) -> Result<DayAnswers, Box<dyn Error>> {
+    let answers = match day {
+        1 => solve::<day1::Day1>(input),
+        2 => solve::<day2::Day2>(input),
+        3 => solve::<day3::Day3>(input),
+        4 => solve::<day4::Day4>(input),
+        5 => solve::<day5::Day5>(input),
+        6 => solve::<day6::Day6>(input),
+        7 => solve::<day7::Day7>(input),
+        8 => solve::<day8::Day8>(input),
+        9 => solve::<day9::Day9>(input),
+        10 => solve::<day10::Day10>(input),
+        11 => solve::<day11::Day11>(input),
+        12 => solve::<day12::Day12>(input),
+        13 => solve::<day13::Day13>(input),
+        14 => solve::<day14::Day14>(input),
+        _ => return Err(format!("day {} is not implemented", day).into()),
+    };
+    answers
+}
+
+fn solve<S: Solution>(input: &str) -> Result<DayAnswers, SolveError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = S::part1(&parsed)?.to_string();
+    let part1_time = start.elapsed();
+
+    let start = Instant::now();
+    let part2 = S::part2(&parsed)?.to_string();
+    let part2_time = start.elapsed();
+
+    Ok(DayAnswers {
+        part1,
+        part2,
+        parse_time,
+        part1_time,
+        part2_time,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_solve_both_parts_given_the_day_and_its_test_file() {
+        let answers = solve_day(1, "../day1/day1_test.txt").unwrap();
+        assert_eq!(answers.part1, "7");
+        assert_eq!(answers.part2, "5");
+
+        let answers = solve_day(12, "../day12/day12_test.txt").unwrap();
+        assert_eq!(answers.part1, "10");
+        assert_eq!(answers.part2, "36");
+    }
+
+    #[test]
+    fn should_get_parse_error_with_the_filename_given_the_wrong_input_file() {
+        let error = solve_day(2, "../day1/day1_test.txt").unwrap_err();
+
+        assert!(error.to_string().starts_with(
+            "error: expected one of forward, up, down, back, surface, turn or repeat, found `199`"
+        ));
+        assert!(error.to_string().contains("../day1/day1_test.txt:1:1"));
+    }
+
+    #[test]
+    fn should_solve_both_parts_given_the_day_and_its_input_from_a_reader() {
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        let answers = solve_day_from_reader(1, input.as_bytes()).unwrap();
+        assert_eq!(answers.part1, "7");
+        assert_eq!(answers.part2, "5");
+
+        let error = solve_day_from_str(2, "forward x").unwrap_err();
+        assert!(error.to_string().contains("<input>:1:9"));
+    }
+
+    #[test]
+    fn should_get_error_given_a_day_not_implemented() {
+        assert!(solve_day(25, "../day1/day1_test.txt").is_err());
+    }
+
+    struct FailingPart2;
+
+    impl Solution for FailingPart2 {
+        type Input = u64;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(input: &str) -> Result<u64, ParseError> {
+            input
+                .trim()
+                .parse()
+                .map_err(|_| ParseError::for_whole_input(input, "a number"))
+        }
+
+        fn part1(input: &u64) -> Result<u64, SolveError> {
+            Ok(*input)
+        }
+
+        fn part2(input: &u64) -> Result<u64, SolveError> {
+            input
+                .checked_mul(2)
+                .ok_or_else(|| "error: the answer overflows".into())
+        }
+    }
+
+    #[test]
+    fn should_get_the_part_error_given_a_part_that_cannot_be_answered() {
+        assert_eq!(solve::<FailingPart2>("21").unwrap().part2, "42");
+
+        let error = solve::<FailingPart2>(&u64::MAX.to_string()).unwrap_err();
+        assert_eq!(error.to_string(), "error: the answer overflows");
+    }
+}
@@ -0,0 +1,200 @@
+mod answers;
+mod bench;
+mod days;
+mod verify;
+
+use answers::{read_answers_from_file, DEFAULT_ANSWERS_FILENAME};
+use bench::{bench_day, get_bench_json, parse_bench_options};
+use days::{get_input_filename, solve_day, solve_day_from_reader, IMPLEMENTED_DAYS};
+use std::{env, io, process};
+use verify::{get_verify_summary, verify_days};
+
+const USAGE: &str = "usage: aoc run <day> [<input file> | -] | <first>..<last> | --all
+       aoc verify [<answers file>]
+       aoc bench [<days> | --all] [--warmup <n>] [--iterations <n>] [--json]";
+
+// Where the puzzle input comes from: each day's own input file, a given file, or stdin.
+#[derive(Debug, PartialEq)]
+enum InputSource {
+    DayFiles,
+    File(String),
+    Stdin,
+}
+
+// `aoc run 9 < input.txt`. Pipes are left alone, as an idle one (cron, CI) would block the
+// run forever; `cat input.txt | aoc run 9 -` reads them explicitly.
+#[cfg(unix)]
+fn is_stdin_redirected_from_file() -> bool {
+    use std::{fs::File, os::fd::AsFd};
+
+    io::stdin()
+        .as_fd()
+        .try_clone_to_owned()
+        .and_then(|fd| File::from(fd).metadata())
+        .map(|metadata| metadata.is_file())
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_stdin_redirected_from_file() -> bool {
+    false
+}
+
+fn parse_input_source(days: &[u32], arg: Option<&str>) -> Result<InputSource, String> {
+    match arg {
+        Some(_) if days.len() != 1 => {
+            Err("an input file can only be given for a single day".to_string())
+        }
+        Some("-") => Ok(InputSource::Stdin),
+        Some(filename) => Ok(InputSource::File(filename.to_string())),
+        None if days.len() == 1 && is_stdin_redirected_from_file() => Ok(InputSource::Stdin),
+        None => Ok(InputSource::DayFiles),
+    }
+}
+
+fn parse_days(arg: &str) -> Result<Vec<u32>, String> {
+    if arg == "--all" {
+        return Ok(IMPLEMENTED_DAYS.to_vec());
+    }
+
+    let days = match arg.split_once("..") {
+        Some((first, last)) => {
+            let first = parse_day(first)?;
+            let last = parse_day(last.trim_start_matches('='))?;
+            if first > last {
+                return Err(format!("invalid day range {}", arg));
+            }
+            (first..=last).collect()
+        }
+        None => vec![parse_day(arg)?],
+    };
+
+    Ok(days)
+}
+
+fn parse_day(arg: &str) -> Result<u32, String> {
+    match arg.trim().parse::<u32>() {
+        Ok(day) if IMPLEMENTED_DAYS.contains(&day) => Ok(day),
+        Ok(day) => Err(format!("day {} is not implemented", day)),
+        Err(_) => Err(format!("invalid day {}", arg)),
+    }
+}
+
+fn run_days(days: &[u32], source: &InputSource) -> bool {
+    let mut all_solved = true;
+    for day in days {
+        let answers = match source {
+            InputSource::DayFiles => solve_day(*day, get_input_filename(*day)),
+            InputSource::File(filename) => solve_day(*day, filename),
+            InputSource::Stdin => solve_day_from_reader(*day, io::stdin().lock()),
+        };
+        match answers {
+            Ok(answers) => {
+                println!("day {} part 1: {}", day, answers.part1);
+                println!("day {} part 2: {}", day, answers.part2);
+            }
+            Err(e) => {
+                eprintln!("day {} failed\n{}", day, e);
+                all_solved = false;
+            }
+        }
+    }
+    all_solved
+}
+
+fn verify(answers_filename: &str) -> Result<bool, String> {
+    let answers = read_answers_from_file(answers_filename).map_err(|e| e.to_string())?;
+    let reports = verify_days(&IMPLEMENTED_DAYS, &answers);
+    for report in &reports {
+        println!("{}", report);
+    }
+    println!("{}", get_verify_summary(&reports));
+    Ok(!reports.iter().any(|report| report.is_regression()))
+}
+
+fn bench(args: &[&str]) -> Result<bool, String> {
+    let (days, args) = match args.first() {
+        Some(arg) if *arg == "--all" || !arg.starts_with("--") => (parse_days(arg)?, &args[1..]),
+        _ => (IMPLEMENTED_DAYS.to_vec(), args),
+    };
+    let options = parse_bench_options(args)?;
+
+    let mut benches = vec![];
+    let mut all_solved = true;
+    for day in days {
+        match bench_day(day, &options) {
+            Ok(bench) if options.json => benches.push(bench),
+            Ok(bench) => print!("{}", bench),
+            Err(e) => {
+                eprintln!("day {} failed\n{}", day, e);
+                all_solved = false;
+            }
+        }
+    }
+    if options.json {
+        println!("{}", get_bench_json(&benches, &options));
+    }
+    Ok(all_solved)
+}
+
+fn run(arg: &str, input: Option<&str>) -> Result<bool, String> {
+    let days = parse_days(arg)?;
+    let source = parse_input_source(&days, input)?;
+    Ok(run_days(&days, &source))
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    let result = match args.iter().map(|x| x.as_str()).collect::<Vec<&str>>()[..] {
+        ["run", arg] => run(arg, None),
+        ["run", arg, input] => run(arg, Some(input)),
+        ["verify"] => verify(DEFAULT_ANSWERS_FILENAME),
+        ["verify", answers_filename] => verify(answers_filename),
+        ["bench", ref bench_args @ ..] => bench(bench_args),
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_days_to_run_given_a_day_a_range_or_all() {
+        assert_eq!(parse_days("5").unwrap(), vec![5]);
+        assert_eq!(parse_days("1..3").unwrap(), vec![1, 2, 3]);
+        assert_eq!(parse_days("1..=3").unwrap(), vec![1, 2, 3]);
+        assert_eq!(parse_days("--all").unwrap(), IMPLEMENTED_DAYS.to_vec());
+
+        assert!(parse_days("3..1").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("five").is_err());
+    }
+
+    #[test]
+    fn should_get_input_source_given_an_optional_input_argument() {
+        assert_eq!(
+            parse_input_source(&[9], Some("-")).unwrap(),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            parse_input_source(&[9], Some("input.txt")).unwrap(),
+            InputSource::File("input.txt".to_string())
+        );
+        assert_eq!(
+            parse_input_source(&[1, 2], None).unwrap(),
+            InputSource::DayFiles
+        );
+
+        assert!(parse_input_source(&[1, 2], Some("input.txt")).is_err());
+    }
+}
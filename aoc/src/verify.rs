@@ -0,0 +1,146 @@
+use crate::answers::ExpectedAnswers;
+use crate::days::{get_input_filename, solve_day};
+use std::{collections::BTreeMap, fmt, time::Duration};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartStatus {
+    Pass,
+    Fail { expected: String, got: String },
+    Missing { got: String },
+    Error(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartReport {
+    pub day: u32,
+    pub part: u32,
+    pub status: PartStatus,
+    pub time: Duration,
+}
+
+impl PartReport {
+    pub fn is_regression(&self) -> bool {
+        matches!(self.status, PartStatus::Fail { .. } | PartStatus::Error(_))
+    }
+}
+
+impl fmt::Display for PartReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "day {} part {}: ", self.day, self.part)?;
+        match &self.status {
+            PartStatus::Pass => write!(f, "PASS")?,
+            PartStatus::Fail { expected, got } => {
+                write!(f, "FAIL expected {:?}, got {:?}", expected, got)?
+            }
+            PartStatus::Missing { got } => write!(f, "MISSING got {:?}", got)?,
+            PartStatus::Error(e) => return write!(f, "FAIL\n{}", e),
+        }
+        write!(f, " ({:.3}ms)", self.time.as_secs_f64() * 1000.0)
+    }
+}
+
+pub fn check_part(
+    day: u32,
+    part: u32,
+    expected: Option<&String>,
+    got: &str,
+    time: Duration,
+) -> PartReport {
+    let status = match expected {
+        Some(expected) if expected == got => PartStatus::Pass,
+        Some(expected) => PartStatus::Fail {
+            expected: expected.clone(),
+            got: got.to_string(),
+        },
+        None => PartStatus::Missing {
+            got: got.to_string(),
+        },
+    };
+    PartReport {
+        day,
+        part,
+        status,
+        time,
+    }
+}
+
+// Solves every given day from its real input file and compares both parts to the manifest.
+pub fn verify_days(days: &[u32], answers: &BTreeMap<u32, ExpectedAnswers>) -> Vec<PartReport> {
+    let mut reports = vec![];
+    for day in days {
+        let expected = answers.get(day).cloned().unwrap_or_default();
+        match solve_day(*day, get_input_filename(*day)) {
+            Ok(solved) => {
+                // parsing is shared by both parts, so it is counted in part 1
+                reports.push(check_part(
+                    *day,
+                    1,
+                    expected.part1.as_ref(),
+                    &solved.part1,
+                    solved.parse_time + solved.part1_time,
+                ));
+                reports.push(check_part(
+                    *day,
+                    2,
+                    expected.part2.as_ref(),
+                    &solved.part2,
+                    solved.part2_time,
+                ));
+            }
+            Err(e) => {
+                for part in [1, 2] {
+                    reports.push(PartReport {
+                        day: *day,
+                        part,
+                        status: PartStatus::Error(e.to_string()),
+                        time: Duration::ZERO,
+                    });
+                }
+            }
+        }
+    }
+    reports
+}
+
+pub fn get_verify_summary(reports: &[PartReport]) -> String {
+    let count = |is_status: fn(&PartStatus) -> bool| {
+        reports
+            .iter()
+            .filter(|report| is_status(&report.status))
+            .count()
+    };
+    format!(
+        "{} passed, {} failed, {} missing",
+        count(|status| matches!(status, PartStatus::Pass)),
+        count(|status| matches!(status, PartStatus::Fail { .. } | PartStatus::Error(_))),
+        count(|status| matches!(status, PartStatus::Missing { .. })),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_pass_fail_or_missing_given_the_expected_answer() {
+        let expected = "1722".to_string();
+        let time = Duration::from_millis(2);
+
+        let pass = check_part(1, 1, Some(&expected), "1722", time);
+        let fail = check_part(1, 1, Some(&expected), "1723", time);
+        let missing = check_part(1, 1, None, "1722", time);
+
+        assert_eq!(pass.status, PartStatus::Pass);
+        assert!(fail.is_regression());
+        assert!(!missing.is_regression());
+        assert_eq!(pass.to_string(), "day 1 part 1: PASS (2.000ms)");
+        assert_eq!(
+            fail.to_string(),
+            "day 1 part 1: FAIL expected \"1722\", got \"1723\" (2.000ms)"
+        );
+        assert_eq!(
+            get_verify_summary(&[pass, fail, missing]),
+            "1 passed, 1 failed, 1 missing"
+        );
+    }
+}
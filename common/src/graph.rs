@@ -0,0 +1,654 @@
+use crate::{input_lines, InputLine, ParseError};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+};
+
+pub type NodeId = usize;
+
+// Node names are interned once, so everything after parsing works on plain indices.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Graph {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    adjacency: Vec<Vec<(NodeId, i64)>>,
+    directed: bool,
+}
+
+// Decides whether a path may be extended with `node`; `path` always starts at the start node.
+pub trait VisitConstraint {
+    fn can_visit(&self, graph: &Graph, path: &[NodeId], node: NodeId) -> bool;
+}
+
+impl<F: Fn(&Graph, &[NodeId], NodeId) -> bool> VisitConstraint for F {
+    fn can_visit(&self, graph: &Graph, path: &[NodeId], node: NodeId) -> bool {
+        self(graph, path, node)
+    }
+}
+
+pub struct Paths<'a, C> {
+    graph: &'a Graph,
+    goal: NodeId,
+    constraint: C,
+    path: Vec<NodeId>,
+    next_edges: Vec<usize>,
+}
+
+impl<C: VisitConstraint> Iterator for Paths<'_, C> {
+    type Item = Vec<NodeId>;
+
+    fn next(&mut self) -> Option<Vec<NodeId>> {
+        while let Some(index) = self.next_edges.last_mut() {
+            let node = self.path[self.path.len() - 1];
+            let edges = &self.graph.adjacency[node];
+            if *index >= edges.len() {
+                self.next_edges.pop();
+                self.path.pop();
+                continue;
+            }
+            let next = edges[*index].0;
+            *index += 1;
+            if !self.constraint.can_visit(self.graph, &self.path, next) {
+                continue;
+            }
+            self.path.push(next);
+            if next == self.goal {
+                let path = self.path.clone();
+                self.path.pop();
+                return Some(path);
+            }
+            self.next_edges.push(0);
+        }
+        None
+    }
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn directed() -> Self {
+        Graph {
+            directed: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn add_node(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.adjacency.push(vec![]);
+        id
+    }
+
+    pub fn node_id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        0..self.names.len()
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: i64) {
+        self.adjacency[from].push((to, weight));
+        if !self.directed && from != to {
+            self.adjacency[to].push((from, weight));
+        }
+    }
+
+    pub fn add_edge_by_name(&mut self, from: &str, to: &str, weight: i64) -> (NodeId, NodeId) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.add_edge(from, to, weight);
+        (from, to)
+    }
+
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, i64)] {
+        &self.adjacency[id]
+    }
+
+    pub fn neighbours(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.adjacency[id].iter().map(|(to, _)| *to)
+    }
+
+    // Nodes in breadth-first order together with their distance in edges from `start`.
+    pub fn bfs(&self, start: NodeId) -> Vec<(NodeId, usize)> {
+        let mut visited = vec![false; self.len()];
+        let mut order = vec![];
+        let mut queue = VecDeque::from([(start, 0)]);
+        visited[start] = true;
+        while let Some((node, distance)) = queue.pop_front() {
+            order.push((node, distance));
+            for next in self.neighbours(node) {
+                if !visited[next] {
+                    visited[next] = true;
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+        order
+    }
+
+    // Nodes in depth-first pre-order, taking edges in the order they were added.
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut visited = vec![false; self.len()];
+        let mut order = vec![];
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+            order.push(node);
+            stack.extend(
+                self.neighbours(node)
+                    .collect::<Vec<NodeId>>()
+                    .into_iter()
+                    .rev(),
+            );
+        }
+        order
+    }
+
+    // Every path from `start` to `goal` the constraint allows; a path stops once it reaches `goal`.
+    pub fn paths(
+        &self,
+        start: NodeId,
+        goal: NodeId,
+        constraint: &(impl VisitConstraint + ?Sized),
+    ) -> Vec<Vec<NodeId>> {
+        let constraint =
+            |graph: &Graph, path: &[NodeId], node| constraint.can_visit(graph, path, node);
+        self.paths_iter(start, goal, constraint).collect()
+    }
+
+    // Same paths as `paths`, found one at a time, so only the current path is kept in memory.
+    pub fn paths_iter<C: VisitConstraint>(
+        &self,
+        start: NodeId,
+        goal: NodeId,
+        constraint: C,
+    ) -> Paths<'_, C> {
+        Paths {
+            graph: self,
+            goal,
+            constraint,
+            path: vec![start],
+            next_edges: vec![0],
+        }
+    }
+
+    // Weakly connected components, each sorted by node id.
+    pub fn connected_components(&self) -> Vec<Vec<NodeId>> {
+        let mut parents = (0..self.len()).collect::<Vec<NodeId>>();
+        fn root(parents: &mut [NodeId], mut node: NodeId) -> NodeId {
+            while parents[node] != node {
+                parents[node] = parents[parents[node]];
+                node = parents[node];
+            }
+            node
+        }
+        for from in self.nodes() {
+            for to in self.neighbours(from) {
+                let (from_root, to_root) = (root(&mut parents, from), root(&mut parents, to));
+                parents[from_root.max(to_root)] = from_root.min(to_root);
+            }
+        }
+
+        let mut components: Vec<Vec<NodeId>> = vec![];
+        let mut component_of_root = HashMap::new();
+        for node in self.nodes() {
+            let node_root = root(&mut parents, node);
+            let index = *component_of_root.entry(node_root).or_insert_with(|| {
+                components.push(vec![]);
+                components.len() - 1
+            });
+            components[index].push(node);
+        }
+        components
+    }
+
+    // Cheapest path and its cost; edge weights must not be negative.
+    pub fn dijkstra(&self, start: NodeId, goal: NodeId) -> Option<(i64, Vec<NodeId>)> {
+        self.a_star(start, goal, |_| 0)
+    }
+
+    // Like dijkstra, guided by a heuristic that never overestimates the remaining cost.
+    pub fn a_star(
+        &self,
+        start: NodeId,
+        goal: NodeId,
+        heuristic: impl Fn(NodeId) -> i64,
+    ) -> Option<(i64, Vec<NodeId>)> {
+        let mut costs = vec![i64::MAX; self.len()];
+        let mut previous = vec![None; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((heuristic(start), 0, start))]);
+        costs[start] = 0;
+        while let Some(Reverse((_, cost, node))) = queue.pop() {
+            if node == goal {
+                let mut path = vec![goal];
+                while let Some(before) = previous[path[path.len() - 1]] {
+                    path.push(before);
+                }
+                path.reverse();
+                return Some((cost, path));
+            }
+            if cost > costs[node] {
+                continue;
+            }
+            for &(next, weight) in self.edges(node) {
+                let next_cost = cost + weight;
+                if next_cost < costs[next] {
+                    costs[next] = next_cost;
+                    previous[next] = Some(node);
+                    queue.push(Reverse((next_cost + heuristic(next), next_cost, next)));
+                }
+            }
+        }
+        None
+    }
+}
+
+// Reads either a DOT `graph`/`digraph` or an edge list with one `a-b`, `a b` or `a b weight`
+// per line. DOT edges may be chained (`a -- b -- c`) and weighted with `[weight=5]`, or by an
+// earlier `edge [weight=5]`; statements are split on `;`, `{` and `}` so they can share lines.
+pub fn parse_graph_by_line(lines: &[String]) -> Result<Graph, ParseError> {
+    let lines = input_lines(lines)
+        .filter(|line| {
+            let text = line.text.trim();
+            !text.is_empty() && !text.starts_with("//") && !text.starts_with('#')
+        })
+        .collect::<Vec<InputLine>>();
+
+    let header = match lines.first() {
+        Some(line) => line.text.trim(),
+        None => return Err(ParseError::for_whole_input("", "at least one edge")),
+    };
+    let header = header.strip_prefix("strict ").unwrap_or(header);
+    if header.starts_with("digraph") {
+        parse_dot_lines(&lines, Graph::directed())
+    } else if header.starts_with("graph") {
+        parse_dot_lines(&lines, Graph::new())
+    } else {
+        parse_edge_list_lines(&lines)
+    }
+}
+
+fn parse_edge_list_lines(lines: &[InputLine]) -> Result<Graph, ParseError> {
+    let mut graph = Graph::new();
+    for line in lines {
+        let text = line.text.trim();
+        let tokens = if text.contains(char::is_whitespace) {
+            text.split_whitespace().collect::<Vec<&str>>()
+        } else {
+            text.split('-').collect::<Vec<&str>>()
+        };
+        let weight = match tokens[..] {
+            [_, _] => 1,
+            [_, _, weight] => line.parse_number::<i64>(weight, "an edge weight")?,
+            _ => return Err(line.error_at(text, "an edge like a-b, a b or a b <weight>")),
+        };
+        let from = line.expect_token(tokens.first().copied(), "a node name")?;
+        let to = line.expect_token(tokens.get(1).copied(), "a second node name")?;
+        graph.add_edge_by_name(from, to, weight);
+    }
+    Ok(graph)
+}
+
+// Byte positions of `pattern` in `text` that are not inside a quoted name.
+fn get_positions_outside_quotes(text: &str, pattern: &str) -> Vec<usize> {
+    let mut positions = vec![];
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for (position, c) in text.char_indices() {
+        match c {
+            '\\' if in_quotes => escaped = !escaped,
+            '"' if !escaped => in_quotes = !in_quotes,
+            _ if !in_quotes && text[position..].starts_with(pattern) => positions.push(position),
+            _ => {}
+        }
+        if c != '\\' {
+            escaped = false;
+        }
+    }
+    positions
+}
+
+fn split_outside_quotes<'a>(text: &'a str, pattern: &str) -> Vec<&'a str> {
+    let mut parts = vec![];
+    let mut start = 0;
+    for position in get_positions_outside_quotes(text, pattern) {
+        parts.push(&text[start..position]);
+        start = position + pattern.len();
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+// The statements of a line, each with the `{`, `}` or `;` after it or None at the end of the
+// line. Separators inside quoted names and attribute lists don't end a statement.
+fn split_dot_statements(text: &str) -> Vec<(&str, Option<&str>)> {
+    let mut statements = vec![];
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut in_attributes = false;
+    let mut escaped = false;
+    for (position, c) in text.char_indices() {
+        match c {
+            '\\' if in_quotes => escaped = !escaped,
+            '"' if !escaped => in_quotes = !in_quotes,
+            _ if in_quotes => {}
+            '[' => in_attributes = true,
+            ']' => in_attributes = false,
+            '{' | '}' | ';' if !in_attributes => {
+                statements.push((&text[start..position], Some(&text[position..position + 1])));
+                start = position + 1;
+            }
+            _ => {}
+        }
+        if c != '\\' {
+            escaped = false;
+        }
+    }
+    statements.push((&text[start..], None));
+    statements
+}
+
+// The weight in an attribute list like `[weight=5, color=red]`, None if it has no weight.
+fn parse_dot_weight(line: &InputLine, attributes: &str) -> Result<Option<i64>, ParseError> {
+    let attributes = match attributes.trim_end().strip_suffix(']') {
+        Some(attributes) => attributes,
+        None => return Err(line.error_at_end("`]`")),
+    };
+    let mut weight = None;
+    for attribute in attributes.split([',', ';']) {
+        if let Some(("weight", value)) = attribute
+            .split_once('=')
+            .map(|(key, value)| (key.trim(), value.trim().trim_matches('"')))
+        {
+            weight = Some(line.parse_number::<i64>(value, "a number as the weight")?);
+        }
+    }
+    Ok(weight)
+}
+
+fn parse_dot_lines(lines: &[InputLine], mut graph: Graph) -> Result<Graph, ParseError> {
+    let mut has_header = false;
+    let mut opened = false;
+    let mut closed = false;
+    // set by `edge [weight=...]` for the edges after it
+    let mut default_weight = 1;
+    for line in lines {
+        for (statement, separator) in split_dot_statements(line.text) {
+            let statement = statement.trim();
+            if !opened {
+                // `strict digraph name` before the `{`, checked by parse_graph_by_line
+                if !statement.is_empty() && has_header {
+                    return Err(line.error_at(statement, "`{`"));
+                }
+                has_header |= !statement.is_empty();
+                match separator {
+                    Some("{") => opened = true,
+                    Some(separator) => return Err(line.error_at(separator, "`{`")),
+                    None => {}
+                }
+                continue;
+            }
+            if closed {
+                if !statement.is_empty() {
+                    return Err(line.error_at(statement, "nothing after the closing `}`"));
+                }
+                if let Some(separator @ ("{" | "}")) = separator {
+                    return Err(line.error_at(separator, "nothing after the closing `}`"));
+                }
+                continue;
+            }
+            match separator {
+                Some(separator @ "{") => return Err(line.error_at(separator, "`;` or `}`")),
+                Some("}") => closed = true,
+                _ => {}
+            }
+            if !statement.is_empty() {
+                parse_dot_statement(line, statement, &mut graph, &mut default_weight)?;
+            }
+        }
+    }
+
+    if !opened {
+        return Err(lines[0].error_at_end("`{`"));
+    }
+    if !closed {
+        return Err(ParseError::for_whole_input("", "a closing `}`"));
+    }
+    Ok(graph)
+}
+
+fn parse_dot_statement(
+    line: &InputLine,
+    text: &str,
+    graph: &mut Graph,
+    default_weight: &mut i64,
+) -> Result<(), ParseError> {
+    let edge_op = if graph.is_directed() { "->" } else { "--" };
+    let wrong_op = if graph.is_directed() { "--" } else { "->" };
+    if let Some(position) = get_positions_outside_quotes(text, wrong_op).first() {
+        return Err(line.error_at(&text[*position..*position + 2], format!("`{}`", edge_op)));
+    }
+
+    let (statement, attributes) = match get_positions_outside_quotes(text, "[").first() {
+        Some(position) => (text[..*position].trim(), Some(&text[*position + 1..])),
+        None => (text, None),
+    };
+    let weight = match attributes {
+        Some(attributes) => parse_dot_weight(line, attributes)?,
+        None => None,
+    };
+    match statement {
+        "edge" => {
+            *default_weight = weight.unwrap_or(*default_weight);
+            return Ok(());
+        }
+        // node and graph defaults like shapes and colours don't change the graph
+        "node" | "graph" => return Ok(()),
+        _ => {}
+    }
+
+    let edge_positions = get_positions_outside_quotes(statement, edge_op);
+    // graph attributes like `rankdir=LR` don't describe nodes or edges
+    if edge_positions.is_empty() && !get_positions_outside_quotes(statement, "=").is_empty() {
+        return Ok(());
+    }
+    let names = split_outside_quotes(statement, edge_op)
+        .into_iter()
+        .map(|name| line.expect_token(Some(name), "a node name"))
+        .collect::<Result<Vec<&str>, ParseError>>()?;
+    let ids = names
+        .iter()
+        .map(|name| graph.add_node(name.trim_matches('"')))
+        .collect::<Vec<NodeId>>();
+    for pair in ids.windows(2) {
+        graph.add_edge(pair[0], pair[1], weight.unwrap_or(*default_weight));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lines_from_str;
+
+    fn get_test_graph() -> Graph {
+        let input = "\
+digraph chiton {
+    a -> b [weight=1];
+    b -> c -> d [weight=2];
+    a -> d [weight=9];
+    e;
+}";
+        parse_graph_by_line(&lines_from_str(input)).unwrap()
+    }
+
+    #[test]
+    fn should_get_graph_given_a_dot_file_or_an_edge_list() {
+        let graph = get_test_graph();
+        assert!(graph.is_directed());
+        assert_eq!(graph.len(), 5);
+        assert_eq!(graph.edges(graph.node_id("b").unwrap()), &[(2, 2)]);
+
+        let graph = parse_graph_by_line(&lines_from_str("start-A\nA end 3")).unwrap();
+        assert!(!graph.is_directed());
+        assert_eq!(graph.neighbours(1).collect::<Vec<NodeId>>(), vec![0, 2]);
+        assert_eq!(graph.edges(2), &[(1, 3)]);
+
+        let error = parse_graph_by_line(&lines_from_str("graph {\n a -> b\n}")).unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 4);
+        assert_eq!(error.expected, "`--`");
+    }
+
+    #[test]
+    fn should_skip_attribute_statements_given_node_edge_and_graph_defaults() {
+        let input = "\
+digraph {
+    graph [rankdir=LR];
+    node [shape=box];
+    edge [weight=3];
+    a -> b;
+}";
+        let graph = parse_graph_by_line(&lines_from_str(input)).unwrap();
+
+        assert_eq!(graph.len(), 2);
+        assert_eq!(graph.node_id("node"), None);
+        assert_eq!(graph.node_id("edge"), None);
+        assert_eq!(graph.node_id("graph"), None);
+        assert_eq!(graph.edges(0), &[(1, 3)]);
+    }
+
+    #[test]
+    fn should_apply_edge_defaults_to_later_edges_given_edge_attribute_statements() {
+        let input = "\
+graph {
+    a -- b;
+    edge [weight=3];
+    b -- c;
+    c -- d [weight=7];
+    edge [color=red];
+    d -- e;
+}";
+        let graph = parse_graph_by_line(&lines_from_str(input)).unwrap();
+        let weight = |from: &str, to: &str| {
+            let (from, to) = (graph.node_id(from).unwrap(), graph.node_id(to).unwrap());
+            graph
+                .edges(from)
+                .iter()
+                .find(|(node, _)| *node == to)
+                .map(|(_, weight)| *weight)
+        };
+
+        assert_eq!(weight("a", "b"), Some(1));
+        assert_eq!(weight("b", "c"), Some(3));
+        assert_eq!(weight("c", "d"), Some(7));
+        assert_eq!(weight("d", "e"), Some(3));
+    }
+
+    #[test]
+    fn should_split_statements_given_a_one_line_graph_or_several_statements_per_line() {
+        let graph =
+            parse_graph_by_line(&lines_from_str("digraph G { a -> b; b -> c [weight=2]; }"))
+                .unwrap();
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.edges(1), &[(2, 2)]);
+
+        let input = "\
+graph G
+{
+    a -- b; b -- c
+    \"x;y\" -- a; }";
+        let graph = parse_graph_by_line(&lines_from_str(input)).unwrap();
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.node_id("b; b"), None);
+        assert_eq!(graph.neighbours(graph.node_id("x;y").unwrap()).count(), 1);
+
+        let error = parse_graph_by_line(&lines_from_str("graph { a -- b } c")).unwrap_err();
+        assert_eq!(error.column, 18);
+        assert_eq!(error.expected, "nothing after the closing `}`");
+        let error = parse_graph_by_line(&lines_from_str("graph\na -- b\n}")).unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (2, "`{`"));
+    }
+
+    #[test]
+    fn should_keep_edge_operators_in_quoted_names_given_a_dot_file() {
+        let input = "\
+graph {
+    \"a->b\" -- \"c--d\" [weight=4];
+    \"x [1]\" -- c;
+}";
+        let graph = parse_graph_by_line(&lines_from_str(input)).unwrap();
+
+        assert_eq!(graph.len(), 4);
+        let a = graph.node_id("a->b").unwrap();
+        let c = graph.node_id("c--d").unwrap();
+        assert_eq!(graph.edges(a), &[(c, 4)]);
+        assert!(graph.node_id("x [1]").is_some());
+    }
+
+    #[test]
+    fn should_traverse_and_split_into_components_given_a_graph() {
+        let graph = get_test_graph();
+
+        assert_eq!(graph.bfs(0), vec![(0, 0), (1, 1), (3, 1), (2, 2)]);
+        assert_eq!(graph.dfs(0), vec![0, 1, 2, 3]);
+        assert_eq!(
+            graph.connected_components(),
+            vec![vec![0, 1, 2, 3], vec![4]]
+        );
+    }
+
+    #[test]
+    fn should_get_paths_and_cheapest_path_given_start_and_goal() {
+        let graph = get_test_graph();
+        let (a, d) = (graph.node_id("a").unwrap(), graph.node_id("d").unwrap());
+
+        let paths = graph.paths(a, d, &|_: &Graph, path: &[NodeId], node| {
+            !path.contains(&node)
+        });
+        assert_eq!(paths, vec![vec![0, 1, 2, 3], vec![0, 3]]);
+
+        let mut lazy_paths = graph.paths_iter(a, d, |_: &Graph, path: &[NodeId], node| {
+            !path.contains(&node)
+        });
+        assert_eq!(lazy_paths.next(), Some(vec![0, 1, 2, 3]));
+        assert_eq!(lazy_paths.count(), 1);
+
+        let short_paths = graph.paths(a, d, &|_: &Graph, path: &[NodeId], _| path.len() < 2);
+        assert_eq!(short_paths, vec![vec![0, 3]]);
+
+        assert_eq!(graph.dijkstra(a, d), Some((5, vec![0, 1, 2, 3])));
+        assert_eq!(
+            graph.a_star(a, d, |node| 3 - node as i64),
+            Some((5, vec![0, 1, 2, 3]))
+        );
+        assert_eq!(graph.dijkstra(d, a), None);
+    }
+}
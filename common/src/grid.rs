@@ -0,0 +1,328 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+// x is the column and y is the row, (0, 0) is the top left cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const NEIGHBOUR_4_OFFSETS: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const NEIGHBOUR_8_OFFSETS: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    // None if the rows do not all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let cells = rows.into_iter().flatten().collect();
+        Some(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    // every cell together with its (x, y), row by row
+    pub fn positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| ((index % width, index / width), cell))
+    }
+
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &NEIGHBOUR_4_OFFSETS)
+    }
+
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &NEIGHBOUR_8_OFFSETS)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(i64, i64)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .map(move |(dx, dy)| (x as i64 + dx, y as i64 + dy))
+            .filter(move |(x, y)| self.contains(*x, *y))
+            .map(|(x, y)| (x as usize, y as usize))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks does not accept 0, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // empty for a column outside the grid
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(height)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn transpose(&self) -> Self {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for x in 0..self.width {
+            cells.extend(self.column(x).cloned());
+        }
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    // mirror left to right
+    pub fn flip_horizontal(&self) -> Self {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for row in self.rows() {
+            cells.extend(row.iter().rev().cloned());
+        }
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells,
+        }
+    }
+
+    // mirror top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for y in (0..self.height).rev() {
+            cells.extend(self.row(y).into_iter().flatten().cloned());
+        }
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells,
+        }
+    }
+
+    // the part of the grid inside the rectangle, clamped to the grid bounds
+    pub fn crop(&self, x: usize, y: usize, width: usize, height: usize) -> Self {
+        let x_end = x.saturating_add(width).min(self.width);
+        let y_end = y.saturating_add(height).min(self.height);
+        let x = x.min(x_end);
+        let y = y.min(y_end);
+        let mut cells = Vec::with_capacity((x_end - x) * (y_end - y));
+        for row in self.rows().take(y_end).skip(y) {
+            cells.extend(row[x..x_end].iter().cloned());
+        }
+        Grid {
+            width: x_end - x,
+            height: y_end - y,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.get(x, y) {
+            Some(cell) => cell,
+            None => panic!(
+                "({}, {}) is outside of the {}x{} grid",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(x, y) {
+            Some(cell) => cell,
+            None => panic!("({}, {}) is outside of the {}x{} grid", x, y, width, height),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            for cell in self.row(y).into_iter().flatten() {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_grid() -> Grid<i64> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn should_get_cells_by_x_and_y_given_rows() {
+        let grid = get_test_grid();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(2, 0), Some(&3));
+        assert_eq!(grid.get(0, 1), Some(&4));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+        assert_eq!(grid[(1, 1)], 5);
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.column(2).collect::<Vec<&i64>>(), vec![&3, &6]);
+
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+    }
+
+    #[test]
+    fn should_get_only_neighbours_inside_the_grid_given_a_non_square_grid() {
+        let grid = get_test_grid();
+
+        let neighbours = grid.neighbours4(0, 0).collect::<Vec<(usize, usize)>>();
+        assert_eq!(neighbours, vec![(1, 0), (0, 1)]);
+
+        let neighbours = grid.neighbours8(2, 1).collect::<Vec<(usize, usize)>>();
+        assert_eq!(neighbours, vec![(1, 0), (2, 0), (1, 1)]);
+
+        assert_eq!(grid.neighbours8(1, 0).count(), 5);
+    }
+
+    #[test]
+    fn should_transform_and_display_the_grid_given_transpose_flip_and_crop() {
+        let grid = get_test_grid();
+
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "321\n654\n");
+        assert_eq!(grid.flip_vertical().to_string(), "456\n123\n");
+        assert_eq!(grid.crop(1, 0, 2, 5).to_string(), "23\n56\n");
+        assert_eq!(grid.crop(5, 5, 2, 2).len(), 0);
+    }
+
+    #[test]
+    fn should_get_no_cells_given_a_column_outside_the_grid() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.column(4).count(), 0);
+        assert_eq!(grid.column(0).collect::<Vec<&i64>>(), vec![&1, &4]);
+    }
+
+    #[test]
+    fn should_get_no_row_given_a_row_outside_the_grid() {
+        let grid = get_test_grid();
+
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.row(usize::MAX), None);
+        assert_eq!(grid.row(0), Some(&[1, 2, 3][..]));
+    }
+
+    #[test]
+    fn should_crop_to_the_grid_given_a_rectangle_reaching_past_usize() {
+        let grid = get_test_grid();
+
+        assert_eq!(grid.crop(1, 1, usize::MAX, usize::MAX).to_string(), "56\n");
+        assert_eq!(grid.crop(usize::MAX, usize::MAX, usize::MAX, 1).len(), 0);
+    }
+}
@@ -1,8 +1,21 @@
+mod big_uint;
+pub mod graph;
+mod grid;
+mod parse_error;
+mod solution;
+
+pub use big_uint::BigUint;
+pub use graph::{parse_graph_by_line, Graph, NodeId, Paths, VisitConstraint};
+pub use grid::Grid;
+pub use parse_error::{input_lines, InputLine, ParseError};
+pub use solution::{Solution, SolveError};
+
 use std::{
-    error::Error,
+    fmt,
     fs::File,
     io::{self, BufRead, BufReader},
     path::Path,
+    process,
 };
 
 #[derive(Debug, Clone)]
@@ -43,46 +56,185 @@ impl<T> Default for Stack<T> {
 }
 
 pub fn lines_from_file(filename: impl AsRef<Path>) -> io::Result<Vec<String>> {
-    BufReader::new(File::open(filename)?).lines().collect()
+    lines_from_reader(BufReader::new(File::open(filename)?))
+}
+
+pub fn lines_from_reader(reader: impl BufRead) -> io::Result<Vec<String>> {
+    reader.lines().collect()
 }
 
-pub fn numbers_from_first_line_in_file(
+pub fn lines_from_str(input: &str) -> Vec<String> {
+    input.lines().map(|line| line.to_string()).collect()
+}
+
+pub fn parse_lines_from_file<T>(
     filename: impl AsRef<Path>,
-) -> Result<Vec<i64>, Box<dyn Error>> {
-    let lines_from_file = lines_from_file(filename)?;
+    parse: impl FnOnce(&[String]) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let filename = filename.as_ref();
+    let lines_from_file =
+        lines_from_file(filename).map_err(|e| ParseError::from_io(filename, e))?;
+    parse(&lines_from_file).map_err(|e| e.with_file(filename))
+}
+
+pub fn parse_lines_from_str<T>(
+    input: &str,
+    parse: impl FnOnce(&[String]) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    parse(&lines_from_str(input))
+}
+
+// Errors from a reader (stdin, a socket, ...) have no filename and are reported against `<input>`.
+pub fn parse_lines_from_reader<T>(
+    reader: impl BufRead,
+    parse: impl FnOnce(&[String]) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let lines = lines_from_reader(reader).map_err(|e| ParseError::from_io("<input>", e))?;
+    parse(&lines)
+}
+
+// Prints the error, which for a ParseError points at the bad input, and exits.
+pub fn exit_on_error<T, E: fmt::Display>(result: Result<T, E>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+pub fn numbers_from_first_line_in_str(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse_lines_from_str(input, numbers_from_first_line)
+}
+
+pub fn numbers_from_first_line_in_reader(reader: impl BufRead) -> Result<Vec<i64>, ParseError> {
+    parse_lines_from_reader(reader, numbers_from_first_line)
+}
+
+pub fn numbers_from_first_line_in_file(filename: impl AsRef<Path>) -> Result<Vec<i64>, ParseError> {
+    parse_lines_from_file(filename, numbers_from_first_line)
+}
+
+pub fn numbers_from_first_line(lines: &[String]) -> Result<Vec<i64>, ParseError> {
+    let first_line = match input_lines(lines).next() {
+        Some(line) => line,
+        None => return Err(ParseError::for_whole_input("", "a line of numbers")),
+    };
     let mut numbers = vec![];
-    for number in lines_from_file[0].trim().split(',').collect::<Vec<&str>>() {
+    for number in first_line.text.trim().split(',') {
         //println!("{:?}", line);
-        let number = number.trim().parse::<i64>()?;
+        let number = first_line.parse_number::<i64>(number, "a number")?;
         numbers.push(number);
     }
     Ok(numbers)
 }
 
+pub fn parse_numbers_without_split_sign_from_lines_in_str(
+    input: &str,
+) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse_lines_from_str(input, parse_numbers_without_split_sign_from_lines)
+}
+
+pub fn parse_numbers_without_split_sign_from_lines_in_reader(
+    reader: impl BufRead,
+) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse_lines_from_reader(reader, parse_numbers_without_split_sign_from_lines)
+}
+
 pub fn parse_numbers_without_split_sign_from_lines_in_file(
     filename: impl AsRef<Path>,
-) -> Result<Vec<Vec<i64>>, Box<dyn Error>> {
-    let lines_from_file = lines_from_file(filename)?;
-    let mut numbers = vec![];
-    for line in lines_from_file {
+) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse_lines_from_file(filename, parse_numbers_without_split_sign_from_lines)
+}
+
+pub fn parse_numbers_without_split_sign_from_lines(
+    lines: &[String],
+) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut numbers: Vec<Vec<i64>> = vec![];
+    for line in input_lines(lines) {
         //println!("{:?}", line);
-        let chars = line.trim().chars();
+        let digits = line.text.trim();
         let mut number = vec![];
-        for c in chars {
-            let bit: i64 = c.to_digit(10).unwrap() as i64;
+        for (index, c) in digits.char_indices() {
+            let bit = match c.to_digit(10) {
+                Some(bit) => bit as i64,
+                None => return Err(line.error_at(&digits[index..index + c.len_utf8()], "a digit")),
+            };
             number.push(bit);
         }
+        if let Some(first) = numbers.first() {
+            if number.len() != first.len() {
+                return Err(line.error_at(
+                    digits,
+                    format!("{} digits as in the first line", first.len()),
+                ));
+            }
+        }
         numbers.push(number);
     }
+    if numbers.is_empty() || numbers[0].is_empty() {
+        return Err(ParseError::for_whole_input(
+            "",
+            "at least one line of digits",
+        ));
+    }
     Ok(numbers)
 }
 
+pub fn parse_number_grid_without_split_sign_from_lines_in_str(
+    input: &str,
+) -> Result<Grid<i64>, ParseError> {
+    parse_lines_from_str(input, parse_number_grid_without_split_sign_from_lines)
+}
+
+pub fn parse_number_grid_without_split_sign_from_lines_in_reader(
+    reader: impl BufRead,
+) -> Result<Grid<i64>, ParseError> {
+    parse_lines_from_reader(reader, parse_number_grid_without_split_sign_from_lines)
+}
+
+pub fn parse_number_grid_without_split_sign_from_lines_in_file(
+    filename: impl AsRef<Path>,
+) -> Result<Grid<i64>, ParseError> {
+    parse_lines_from_file(filename, parse_number_grid_without_split_sign_from_lines)
+}
+
+pub fn parse_number_grid_without_split_sign_from_lines(
+    lines: &[String],
+) -> Result<Grid<i64>, ParseError> {
+    let numbers = parse_numbers_without_split_sign_from_lines(lines)?;
+    // every line has the same number of digits once it is parsed
+    Ok(Grid::from_rows(numbers).unwrap_or_else(|| Grid::new(0, 0, 0)))
+}
+
+pub fn parse_strings_without_split_sign_from_lines_in_str(
+    input: &str,
+) -> Result<Vec<Vec<String>>, ParseError> {
+    parse_lines_from_str(input, |lines| {
+        Ok(parse_strings_without_split_sign_from_lines(lines))
+    })
+}
+
+pub fn parse_strings_without_split_sign_from_lines_in_reader(
+    reader: impl BufRead,
+) -> Result<Vec<Vec<String>>, ParseError> {
+    parse_lines_from_reader(reader, |lines| {
+        Ok(parse_strings_without_split_sign_from_lines(lines))
+    })
+}
+
 pub fn parse_strings_without_split_sign_from_lines_in_file(
     filename: impl AsRef<Path>,
-) -> Result<Vec<Vec<String>>, Box<dyn Error>> {
-    let lines_from_file = lines_from_file(filename)?;
+) -> Result<Vec<Vec<String>>, ParseError> {
+    parse_lines_from_file(filename, |lines| {
+        Ok(parse_strings_without_split_sign_from_lines(lines))
+    })
+}
+
+pub fn parse_strings_without_split_sign_from_lines(lines: &[String]) -> Vec<Vec<String>> {
     let mut strs = vec![];
-    for line in lines_from_file {
+    for line in lines {
         //println!("{:?}", line);
         let chars = line.trim().chars();
         let mut str = vec![];
@@ -92,24 +244,42 @@ pub fn parse_strings_without_split_sign_from_lines_in_file(
         }
         strs.push(str);
     }
-    Ok(strs)
-}
-
-pub fn get_extended_metrix(metrix: &[Vec<i64>]) -> Vec<Vec<i64>> {
-    let height = metrix.len() + 2;
-    let width = metrix[0].len() + 2;
-    let mut extended_metrix = vec![vec![10; width]; height];
-    for (index, extended_row) in extended_metrix[1..height - 1].iter_mut().enumerate() {
-        extended_row.splice(1..width - 1, metrix[index].iter().cloned());
-    }
-    extended_metrix
+    strs
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {
         let result = 2 + 2;
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn should_parse_the_same_numbers_given_a_str_or_a_reader() {
+        let input = "2199943210\n3987894921\n";
+
+        let from_str = parse_number_grid_without_split_sign_from_lines_in_str(input).unwrap();
+        let from_reader =
+            parse_number_grid_without_split_sign_from_lines_in_reader(input.as_bytes()).unwrap();
+
+        assert_eq!(from_str, from_reader);
+        assert_eq!(from_str.width(), 10);
+        assert_eq!(from_str[(9, 1)], 1);
+        assert_eq!(
+            numbers_from_first_line_in_reader("16,1,2\n".as_bytes()).unwrap(),
+            vec![16, 1, 2]
+        );
+    }
+
+    #[test]
+    fn should_report_errors_against_the_input_given_a_reader() {
+        let error = numbers_from_first_line_in_reader("1,x".as_bytes()).unwrap_err();
+
+        assert_eq!(error.file, "<input>");
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 3);
+    }
 }
@@ -0,0 +1,182 @@
+use std::{error::Error, fmt, io, path::Path, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    pub line_text: String,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    // line and column are 1-based; line 0 means the error is about the input as a whole
+    pub fn new(
+        line: usize,
+        column: usize,
+        line_text: impl Into<String>,
+        text: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            file: "<input>".to_string(),
+            line,
+            column,
+            line_text: line_text.into(),
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn for_whole_input(text: impl Into<String>, expected: impl Into<String>) -> Self {
+        ParseError::new(0, 0, "", text, expected)
+    }
+
+    pub fn from_io(filename: impl AsRef<Path>, e: io::Error) -> Self {
+        ParseError::for_whole_input(e.to_string(), "a readable input file").with_file(filename)
+    }
+
+    pub fn with_file(mut self, filename: impl AsRef<Path>) -> Self {
+        self.file = filename.as_ref().display().to_string();
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let found = if self.text.is_empty() {
+            "end of line".to_string()
+        } else {
+            format!("`{}`", self.text)
+        };
+        writeln!(f, "error: expected {}, found {}", self.expected, found)?;
+
+        if self.line == 0 {
+            return write!(f, " --> {}", self.file);
+        }
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(
+            f,
+            "{}--> {}:{}:{}",
+            gutter, self.file, self.line, self.column
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.line_text)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column.saturating_sub(1)),
+            "^".repeat(self.text.chars().count().max(1))
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone, Copy)]
+pub struct InputLine<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+pub fn input_lines(lines: &[String]) -> impl Iterator<Item = InputLine<'_>> {
+    lines.iter().enumerate().map(|(index, text)| InputLine {
+        number: index + 1,
+        text,
+    })
+}
+
+impl<'a> InputLine<'a> {
+    // token has to be a slice of this line (e.g. from split or trim) to get the right column
+    pub fn column_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let position = token.as_ptr() as usize;
+        if position < start || position > start + self.text.len() {
+            return 1;
+        }
+        self.text[..position - start].chars().count() + 1
+    }
+
+    pub fn error_at(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(
+            self.number,
+            self.column_of(token),
+            self.text,
+            token,
+            expected,
+        )
+    }
+
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::new(
+            self.number,
+            self.text.trim_end().chars().count() + 1,
+            self.text,
+            "",
+            expected,
+        )
+    }
+
+    pub fn expect_token(
+        &self,
+        token: Option<&'a str>,
+        expected: impl Into<String>,
+    ) -> Result<&'a str, ParseError> {
+        match token {
+            Some(token) if !token.trim().is_empty() => Ok(token.trim()),
+            Some(token) => Err(self.error_at(token, expected)),
+            None => Err(self.error_at_end(expected)),
+        }
+    }
+
+    pub fn parse_number<T: FromStr>(
+        &self,
+        token: &str,
+        expected: impl Into<String>,
+    ) -> Result<T, ParseError> {
+        let token = token.trim();
+        token
+            .parse::<T>()
+            .map_err(|_| self.error_at(token, expected))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_compiler_style_diagnostic_given_a_bad_token_in_a_line() {
+        let lines = vec!["forward 5".to_string(), "down x".to_string()];
+        let line = input_lines(&lines).nth(1).unwrap();
+        let token = line.text.split_whitespace().nth(1).unwrap();
+
+        let error = line
+            .parse_number::<i64>(token, "a number")
+            .unwrap_err()
+            .with_file("day2_input.txt");
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 6);
+        assert_eq!(
+            error.to_string(),
+            "error: expected a number, found `x`\n --> day2_input.txt:2:6\n  |\n2 | down x\n  |      ^"
+        );
+    }
+
+    #[test]
+    fn should_point_at_the_end_of_line_given_a_missing_token() {
+        let lines = vec!["forward".to_string()];
+        let line = input_lines(&lines).next().unwrap();
+
+        let error = line
+            .expect_token(line.text.split_whitespace().nth(1), "a distance")
+            .unwrap_err();
+
+        assert_eq!(error.column, 8);
+        assert_eq!(error.text, "");
+    }
+}
@@ -0,0 +1,27 @@
+use crate::ParseError;
+use std::{error::Error, fmt, io::BufRead};
+
+// Why a part has no answer for an input that parsed, such as a count that overflows.
+pub type SolveError = Box<dyn Error>;
+
+// One puzzle day: the raw input is parsed once, then both parts are answered from it.
+pub trait Solution {
+    type Input;
+    type Answer1: fmt::Display;
+    type Answer2: fmt::Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError>;
+
+    fn parse_reader(mut reader: impl BufRead) -> Result<Self::Input, ParseError>
+    where
+        Self: Sized,
+    {
+        let mut input = String::new();
+        reader
+            .read_to_string(&mut input)
+            .map_err(|e| ParseError::from_io("<input>", e))?;
+        Self::parse(&input)
+    }
+}
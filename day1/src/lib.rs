@@ -0,0 +1,273 @@
+use common::{
+    input_lines, lines_from_str, parse_lines_from_file, parse_lines_from_reader,
+    parse_lines_from_str, InputLine, ParseError, Solution, SolveError,
+};
+use std::{cmp::Ordering, collections::VecDeque, io::BufRead, path::Path};
+
+const WINDOW_SIZE: usize = 3;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i64>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        parse_nums_by_line(&lines_from_str(input))
+    }
+
+    fn part1(nums: &Vec<i64>) -> Result<usize, SolveError> {
+        Ok(get_sonar_measurement_increase_count(nums))
+    }
+
+    fn part2(nums: &Vec<i64>) -> Result<usize, SolveError> {
+        Ok(get_sonar_measurement_increase_count_by_sliding_window(nums))
+    }
+}
+
+pub fn read_nums_from_file_by_line(filename: impl AsRef<Path>) -> Result<Vec<i64>, ParseError> {
+    parse_lines_from_file(filename, parse_nums_by_line)
+}
+
+pub fn read_nums_from_str_by_line(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse_lines_from_str(input, parse_nums_by_line)
+}
+
+pub fn read_nums_from_reader_by_line(reader: impl BufRead) -> Result<Vec<i64>, ParseError> {
+    parse_lines_from_reader(reader, parse_nums_by_line)
+}
+
+pub fn parse_nums_by_line(lines: &[String]) -> Result<Vec<i64>, ParseError> {
+    let mut nums = vec![];
+    for line in input_lines(lines) {
+        //println!("{:?}", line);
+        let num = line.parse_number::<i64>(line.text, "a depth measurement")?;
+        nums.push(num);
+    }
+    if nums.is_empty() {
+        return Err(ParseError::for_whole_input(
+            "",
+            "at least one depth measurement",
+        ));
+    }
+    Ok(nums)
+}
+
+pub fn get_sonar_measurement_increase_count(nums: &[i64]) -> usize {
+    analyse_sonar_measurements(nums.iter().copied(), 1).map_or(0, |report| report.increases)
+}
+
+pub fn get_sonar_measurement_increase_count_by_sliding_window(nums: &[i64]) -> usize {
+    analyse_sonar_measurements(nums.iter().copied(), WINDOW_SIZE)
+        .map_or(0, |report| report.increases)
+}
+
+// Every comparison is between the sums of two consecutive windows of readings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SonarReport {
+    pub windows: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    // most increases in a row
+    pub longest_increasing_run: usize,
+    // biggest depth gained from one window to the next
+    pub max_descent: i64,
+}
+
+// Keeps only the current window and a running sum, so readings can be streamed through it.
+#[derive(Debug, Clone)]
+pub struct SonarAnalyser {
+    window_size: usize,
+    window: VecDeque<i64>,
+    sum: i64,
+    previous_sum: Option<i64>,
+    increasing_run: usize,
+    report: SonarReport,
+}
+
+impl SonarAnalyser {
+    // None for a window of 0 readings, which has no sums to compare.
+    pub fn new(window_size: usize) -> Option<Self> {
+        if window_size == 0 {
+            return None;
+        }
+        Some(SonarAnalyser {
+            window_size,
+            window: VecDeque::with_capacity(window_size + 1),
+            sum: 0,
+            previous_sum: None,
+            increasing_run: 0,
+            report: SonarReport::default(),
+        })
+    }
+
+    pub fn push(&mut self, reading: i64) {
+        self.window.push_back(reading);
+        self.sum += reading;
+        if self.window.len() > self.window_size {
+            self.sum -= self.window.pop_front().unwrap_or(0);
+        }
+        if self.window.len() < self.window_size {
+            return;
+        }
+
+        self.report.windows += 1;
+        if let Some(previous_sum) = self.previous_sum {
+            match self.sum.cmp(&previous_sum) {
+                Ordering::Greater => {
+                    self.report.increases += 1;
+                    self.increasing_run += 1;
+                    self.report.longest_increasing_run =
+                        self.report.longest_increasing_run.max(self.increasing_run);
+                    self.report.max_descent = self.report.max_descent.max(self.sum - previous_sum);
+                }
+                Ordering::Less => {
+                    self.report.decreases += 1;
+                    self.increasing_run = 0;
+                }
+                Ordering::Equal => {
+                    self.report.plateaus += 1;
+                    self.increasing_run = 0;
+                }
+            }
+        }
+        self.previous_sum = Some(self.sum);
+    }
+
+    pub fn report(&self) -> &SonarReport {
+        &self.report
+    }
+}
+
+pub fn analyse_sonar_measurements(
+    readings: impl IntoIterator<Item = i64>,
+    window_size: usize,
+) -> Option<SonarReport> {
+    let mut analyser = SonarAnalyser::new(window_size)?;
+    for reading in readings {
+        analyser.push(reading);
+    }
+    Some(analyser.report)
+}
+
+// Reads one line at a time, so the depth log never has to fit in memory.
+pub fn analyse_sonar_measurements_from_reader(
+    reader: impl BufRead,
+    window_size: usize,
+) -> Result<SonarReport, ParseError> {
+    let mut analyser = SonarAnalyser::new(window_size).ok_or_else(|| {
+        ParseError::for_whole_input(window_size.to_string(), "a window of at least one reading")
+    })?;
+    for (index, text) in reader.lines().enumerate() {
+        let text = text.map_err(|e| ParseError::from_io("<input>", e))?;
+        let line = InputLine {
+            number: index + 1,
+            text: &text,
+        };
+        analyser.push(line.parse_number::<i64>(line.text, "a depth measurement")?);
+    }
+    Ok(analyser.report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // TODO: is this an integration test?
+    fn should_get_all_nums_from_file_by_line_given_the_filename() {
+        let filename = "day1_test.txt";
+        let nums = read_nums_from_file_by_line(filename).unwrap();
+
+        assert_eq!(nums.len(), 10);
+    }
+
+    #[test]
+    fn should_get_right_increase_count_given_the_sonar_measurement_numbers() {
+        let nums = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        let count = get_sonar_measurement_increase_count(&nums);
+        assert_eq!(count, 7);
+    }
+
+    #[test]
+    fn should_get_right_increase_count_by_sliding_window_given_the_sonar_measurement_numbers() {
+        let nums = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        let count = get_sonar_measurement_increase_count_by_sliding_window(&nums);
+        assert_eq!(count, 5);
+    }
+
+    #[test]
+    fn should_get_sonar_report_given_any_window_size() {
+        let nums = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+        let report = analyse_sonar_measurements(nums.clone(), 1).unwrap();
+        assert_eq!(
+            report,
+            SonarReport {
+                windows: 10,
+                increases: 7,
+                decreases: 2,
+                plateaus: 0,
+                longest_increasing_run: 3,
+                max_descent: 33,
+            }
+        );
+
+        let report = analyse_sonar_measurements(nums.clone(), 3).unwrap();
+        assert_eq!(report.windows, 8);
+        assert_eq!(report.increases, 5);
+        assert_eq!(report.plateaus, 1);
+
+        let report = analyse_sonar_measurements(nums.clone(), 11).unwrap();
+        assert_eq!(report, SonarReport::default());
+    }
+
+    #[test]
+    fn should_get_no_report_given_a_window_of_zero_readings() {
+        let nums = vec![199, 200, 208];
+
+        assert!(SonarAnalyser::new(0).is_none());
+        assert_eq!(analyse_sonar_measurements(nums, 0), None);
+
+        let error = analyse_sonar_measurements_from_reader("199\n200\n".as_bytes(), 0).unwrap_err();
+        assert_eq!(error.text, "0");
+        assert_eq!(error.expected, "a window of at least one reading");
+    }
+
+    #[test]
+    fn should_stream_readings_given_a_reader_or_empty_input() {
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        let report = analyse_sonar_measurements_from_reader(input.as_bytes(), 3).unwrap();
+        assert_eq!(report.increases, 5);
+
+        assert_eq!(get_sonar_measurement_increase_count(&[]), 0);
+        assert_eq!(
+            get_sonar_measurement_increase_count_by_sliding_window(&[]),
+            0
+        );
+
+        let error = analyse_sonar_measurements_from_reader("199\n2x0\n".as_bytes(), 1).unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_test_input_through_the_solution_trait() {
+        let input = std::fs::read_to_string("day1_test.txt").unwrap();
+        let parsed = Day1::parse(&input).unwrap();
+
+        assert_eq!(Day1::part1(&parsed).unwrap(), 7);
+        assert_eq!(Day1::part2(&parsed).unwrap(), 5);
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_example_input_from_a_reader() {
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        let parsed = Day1::parse_reader(input.as_bytes()).unwrap();
+
+        assert_eq!(parsed, read_nums_from_str_by_line(input).unwrap());
+        assert_eq!(Day1::part1(&parsed).unwrap(), 7);
+        assert_eq!(Day1::part2(&parsed).unwrap(), 5);
+    }
+}
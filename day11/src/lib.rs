@@ -0,0 +1,171 @@
+use common::{
+    lines_from_str, parse_number_grid_without_split_sign_from_lines,
+    parse_number_grid_without_split_sign_from_lines_in_file,
+    parse_number_grid_without_split_sign_from_lines_in_reader,
+    parse_number_grid_without_split_sign_from_lines_in_str, Grid, ParseError, Solution, SolveError,
+};
+use std::{collections::HashSet, io::BufRead, path::Path};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Grid<i64>, ParseError> {
+        parse_number_grid_without_split_sign_from_lines(&lines_from_str(input))
+    }
+
+    fn part1(octopuses: &Grid<i64>) -> Result<i64, SolveError> {
+        Ok(get_octopuses_snapshot(octopuses.clone(), 100)
+            .1
+            .iter()
+            .sum::<i64>())
+    }
+
+    fn part2(octopuses: &Grid<i64>) -> Result<i64, SolveError> {
+        Ok(get_first_full_octopus_flash_step(octopuses.clone()))
+    }
+}
+
+pub fn read_octopuses_metrix_from_file(
+    filename: impl AsRef<Path>,
+) -> Result<Grid<i64>, ParseError> {
+    parse_number_grid_without_split_sign_from_lines_in_file(filename)
+}
+
+pub fn read_octopuses_metrix_from_str(input: &str) -> Result<Grid<i64>, ParseError> {
+    parse_number_grid_without_split_sign_from_lines_in_str(input)
+}
+
+pub fn read_octopuses_metrix_from_reader(reader: impl BufRead) -> Result<Grid<i64>, ParseError> {
+    parse_number_grid_without_split_sign_from_lines_in_reader(reader)
+}
+
+pub fn added_one_to_metrix(mut octopuses: Grid<i64>) -> (Grid<i64>, i64) {
+    let mut flashed = HashSet::new();
+    for octopus in octopuses.iter_mut() {
+        *octopus += 1;
+    }
+
+    let mut check_list = octopuses
+        .positions()
+        .filter(|(_, octopus)| **octopus > 9)
+        .map(|(position, _)| position)
+        .collect::<Vec<(usize, usize)>>();
+    while let Some(position) = check_list.pop() {
+        if octopuses[position] > 9 {
+            octopuses[position] = 0;
+            flashed.insert(position);
+
+            let (x, y) = position;
+            let neighbours = octopuses
+                .neighbours8(x, y)
+                .filter(|neighbour| !flashed.contains(neighbour))
+                .collect::<Vec<(usize, usize)>>();
+            for neighbour in neighbours {
+                octopuses[neighbour] += 1;
+                check_list.push(neighbour);
+            }
+        }
+    }
+    //println!("the current octopuses is {:?}\n", octopuses);
+    (octopuses, flashed.len() as i64)
+}
+
+pub fn get_octopuses_snapshot(initial_octopuses: Grid<i64>, step: i64) -> (Grid<i64>, Vec<i64>) {
+    let mut flashed_list = vec![];
+    let mut octopuses = initial_octopuses;
+    for _ in 0..step {
+        let (o, flashed) = added_one_to_metrix(octopuses);
+        octopuses = o;
+        flashed_list.push(flashed);
+    }
+    (octopuses, flashed_list)
+}
+
+pub fn get_first_full_octopus_flash_step(initial_octopuses: Grid<i64>) -> i64 {
+    let octopus_count = initial_octopuses.len();
+    let mut step = 0;
+    let mut flashed = 0;
+    let mut octopuses = initial_octopuses;
+    while flashed != octopus_count {
+        let result = added_one_to_metrix(octopuses);
+        step += 1;
+        octopuses = result.0;
+        flashed = result.1 as usize;
+    }
+    step
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_octopuses_metrix_from_file_given_the_filename() {
+        let filename = "day11_test.txt";
+        let octopuses = read_octopuses_metrix_from_file(filename).unwrap();
+
+        assert_eq!(octopuses.height(), 10);
+        assert_eq!(octopuses.width(), 10);
+    }
+
+    #[test]
+    fn should_get_snapshot_of_octopuses_metrix_given_the_initial_metrix_and_step_number() {
+        let filename = "day11_test.txt";
+        let octopuses = read_octopuses_metrix_from_file(filename).unwrap();
+
+        let snapshot5 = vec![
+            vec![4, 4, 8, 4, 1, 4, 4, 0, 0, 0],
+            vec![2, 0, 4, 4, 1, 4, 4, 0, 0, 0],
+            vec![2, 2, 5, 3, 3, 3, 3, 4, 9, 3],
+            vec![1, 1, 5, 2, 3, 3, 3, 2, 7, 4],
+            vec![1, 1, 8, 7, 3, 0, 3, 2, 8, 5],
+            vec![1, 1, 6, 4, 6, 3, 3, 2, 3, 3],
+            vec![1, 1, 5, 3, 4, 7, 2, 2, 3, 1],
+            vec![6, 6, 4, 3, 3, 5, 2, 2, 3, 3],
+            vec![2, 6, 4, 3, 3, 5, 8, 3, 2, 2],
+            vec![2, 2, 4, 3, 3, 4, 1, 3, 2, 2],
+        ];
+        assert_eq!(
+            get_octopuses_snapshot(octopuses.clone(), 5).0,
+            Grid::from_rows(snapshot5).unwrap()
+        );
+
+        assert_eq!(
+            get_octopuses_snapshot(octopuses.clone(), 10)
+                .1
+                .iter()
+                .sum::<i64>(),
+            204
+        );
+
+        assert_eq!(
+            get_octopuses_snapshot(octopuses, 100).1.iter().sum::<i64>(),
+            1656
+        );
+    }
+
+    #[test]
+    fn should_get_right_first_full_octopuses_flash_step_given_octopuses_metrix() {
+        let filename = "day11_test.txt";
+        let octopuses = read_octopuses_metrix_from_file(filename).unwrap();
+
+        let step = get_first_full_octopus_flash_step(octopuses);
+        assert_eq!(step, 195);
+
+        let octopuses = Grid::from_rows(vec![vec![9, 9], vec![9, 9], vec![9, 9]]).unwrap();
+        assert_eq!(get_first_full_octopus_flash_step(octopuses), 1);
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_test_input_through_the_solution_trait() {
+        let input = std::fs::read_to_string("day11_test.txt").unwrap();
+        let parsed = Day11::parse(&input).unwrap();
+
+        assert_eq!(Day11::part1(&parsed).unwrap(), 1656);
+        assert_eq!(Day11::part2(&parsed).unwrap(), 195);
+    }
+}
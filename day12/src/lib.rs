@@ -0,0 +1,375 @@
+use common::{
+    input_lines, lines_from_str, parse_lines_from_file, parse_lines_from_reader,
+    parse_lines_from_str, Graph, NodeId, ParseError, Solution, SolveError,
+};
+use std::{collections::HashMap, io::BufRead, path::Path};
+
+mod visit_policy;
+
+pub use visit_policy::{
+    MaxLength, OneSmallCaveTwice, PassingThrough, SmallCavesAtMost, SmallCavesOnce,
+    SmallCavesTwiceAtMost, VisitPolicy,
+};
+
+#[derive(Debug, Clone)]
+pub struct CaveGraph {
+    pub graph: Graph,
+    pub start: NodeId,
+    pub end: NodeId,
+}
+
+impl CaveGraph {
+    pub fn is_small_cave(&self, cave: NodeId) -> bool {
+        self.graph.name(cave).chars().all(char::is_lowercase)
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = CaveGraph;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<CaveGraph, ParseError> {
+        parse_cave_graph_by_line(&lines_from_str(input))
+    }
+
+    fn part1(cave_graph: &CaveGraph) -> Result<u64, SolveError> {
+        Ok(count_paths_from_start_to_end(cave_graph, false))
+    }
+
+    fn part2(cave_graph: &CaveGraph) -> Result<u64, SolveError> {
+        Ok(count_paths_from_start_to_end(cave_graph, true))
+    }
+}
+
+pub fn read_cave_graph_from_file(filename: impl AsRef<Path>) -> Result<CaveGraph, ParseError> {
+    parse_lines_from_file(filename, parse_cave_graph_by_line)
+}
+
+pub fn read_cave_graph_from_str(input: &str) -> Result<CaveGraph, ParseError> {
+    parse_lines_from_str(input, parse_cave_graph_by_line)
+}
+
+pub fn read_cave_graph_from_reader(reader: impl BufRead) -> Result<CaveGraph, ParseError> {
+    parse_lines_from_reader(reader, parse_cave_graph_by_line)
+}
+
+pub fn parse_cave_graph_by_line(lines_from_file: &[String]) -> Result<CaveGraph, ParseError> {
+    let mut graph = Graph::new();
+
+    for line in input_lines(lines_from_file) {
+        let mut splits = line.text.trim().split('-');
+        let node1 = line.expect_token(splits.next(), "a cave name")?;
+        let node2 = line.expect_token(splits.next(), "a second cave name after -")?;
+        if let Some(extra) = splits.next() {
+            return Err(line.error_at(extra, "end of line"));
+        }
+        if !node1.chars().all(char::is_alphabetic) {
+            return Err(line.error_at(node1, "a cave name of letters"));
+        }
+        if !node2.chars().all(char::is_alphabetic) {
+            return Err(line.error_at(node2, "a cave name of letters"));
+        }
+        if !node1.chars().all(char::is_lowercase) && !node2.chars().all(char::is_lowercase) {
+            return Err(line.error_at(
+                line.text.trim(),
+                "a small cave on at least one side of the connection",
+            ));
+        }
+        graph.add_edge_by_name(node1, node2, 1);
+    }
+
+    let cave_id = |cave| {
+        graph.node_id(cave).ok_or_else(|| {
+            ParseError::for_whole_input("", format!("a connection with the {} cave", cave))
+        })
+    };
+    let start = cave_id("start")?;
+    let end = cave_id("end")?;
+
+    Ok(CaveGraph { graph, start, end })
+}
+
+pub fn get_paths_with_policy(
+    cave_graph: &CaveGraph,
+    policy: &(impl VisitPolicy + ?Sized),
+) -> Vec<Vec<NodeId>> {
+    get_paths_iter_with_policy(cave_graph, policy).collect()
+}
+
+// Finds the paths one at a time instead of keeping all of them around.
+pub fn get_paths_iter_with_policy<'a>(
+    cave_graph: &'a CaveGraph,
+    policy: &'a (impl VisitPolicy + ?Sized),
+) -> impl Iterator<Item = Vec<NodeId>> + 'a {
+    let can_visit = move |_: &Graph, path: &[NodeId], cave: NodeId| {
+        cave != cave_graph.start && policy.can_visit(cave_graph, path, cave)
+    };
+
+    cave_graph
+        .graph
+        .paths_iter(cave_graph.start, cave_graph.end, can_visit)
+        .filter(move |path| policy.accepts(cave_graph, path))
+}
+
+// Counts the paths of the two built-in policies without building them. The number of paths
+// from a cave only depends on the small caves visited so far and whether one was visited
+// twice, so it is memoised on exactly that.
+pub fn count_paths_from_start_to_end(cave_graph: &CaveGraph, has_longer_time: bool) -> u64 {
+    let small_caves = cave_graph
+        .graph
+        .nodes()
+        .filter(|cave| cave_graph.is_small_cave(*cave))
+        .collect::<Vec<NodeId>>();
+    if small_caves.len() > 128 {
+        return match has_longer_time {
+            true => get_paths_iter_with_policy(cave_graph, &OneSmallCaveTwice).count() as u64,
+            false => get_paths_iter_with_policy(cave_graph, &SmallCavesOnce).count() as u64,
+        };
+    }
+
+    let mut bits = vec![None; cave_graph.graph.len()];
+    for (bit, cave) in small_caves.into_iter().enumerate() {
+        bits[cave] = Some(1u128 << bit);
+    }
+    let visited = bits[cave_graph.start].unwrap_or(0);
+    let mut memo = HashMap::new();
+    count_paths_from_cave(
+        cave_graph,
+        &bits,
+        &mut memo,
+        (cave_graph.start, visited, !has_longer_time),
+    )
+}
+
+fn count_paths_from_cave(
+    cave_graph: &CaveGraph,
+    bits: &[Option<u128>],
+    memo: &mut HashMap<(NodeId, u128, bool), u64>,
+    state: (NodeId, u128, bool),
+) -> u64 {
+    if let Some(count) = memo.get(&state) {
+        return *count;
+    }
+    let (cave, visited, twice_used) = state;
+
+    let mut count = 0;
+    for next in cave_graph.graph.neighbours(cave) {
+        if next == cave_graph.start {
+            continue;
+        }
+        if next == cave_graph.end {
+            count += 1;
+            continue;
+        }
+        let next_state = match bits[next] {
+            None => (next, visited, twice_used),
+            Some(bit) if visited & bit == 0 => (next, visited | bit, twice_used),
+            Some(_) if !twice_used => (next, visited, true),
+            Some(_) => continue,
+        };
+        count += count_paths_from_cave(cave_graph, bits, memo, next_state);
+    }
+
+    memo.insert(state, count);
+    count
+}
+
+pub fn get_path_names(cave_graph: &CaveGraph, paths: Vec<Vec<NodeId>>) -> Vec<Vec<String>> {
+    paths
+        .into_iter()
+        .map(|path| {
+            path.into_iter()
+                .map(|cave| cave_graph.graph.name(cave).to_string())
+                .collect()
+        })
+        .collect()
+}
+
+pub fn get_all_paths_from_start_to_end(
+    cave_graph: CaveGraph,
+    has_longer_time: bool,
+) -> Vec<Vec<String>> {
+    let paths = match has_longer_time {
+        true => get_paths_with_policy(&cave_graph, &OneSmallCaveTwice),
+        false => get_paths_with_policy(&cave_graph, &SmallCavesOnce),
+    };
+    get_path_names(&cave_graph, paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_cave_graph_from_file_given_the_filename() {
+        let filename = "day12_test.txt";
+        let cave_graph = read_cave_graph_from_file(filename).unwrap();
+
+        assert_eq!(cave_graph.graph.len(), 6);
+
+        let filename = "day12_test_2.txt";
+        let cave_graph = read_cave_graph_from_file(filename).unwrap();
+
+        assert_eq!(cave_graph.graph.len(), 7);
+    }
+
+    #[test]
+    fn should_get_parse_error_given_two_big_caves_connected_or_no_start_cave() {
+        let lines = vec!["start-A".to_string(), "A-B".to_string()];
+        let error = parse_cave_graph_by_line(&lines).err().unwrap();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.text, "A-B");
+
+        let lines = vec!["a-end".to_string()];
+        let error = parse_cave_graph_by_line(&lines).err().unwrap();
+
+        assert_eq!(error.line, 0);
+        assert_eq!(error.expected, "a connection with the start cave");
+    }
+
+    #[test]
+    fn should_get_all_paths_from_start_to_end_given_adjacency_matrix() {
+        let filename = "day12_test.txt";
+        let cave_graph = read_cave_graph_from_file(filename).unwrap();
+
+        let paths = get_all_paths_from_start_to_end(cave_graph, false);
+
+        //println!("paths are {:?}", paths);
+        assert_eq!(paths.len(), 10);
+
+        let filename = "day12_test_2.txt";
+        let cave_graph = read_cave_graph_from_file(filename).unwrap();
+
+        let paths = get_all_paths_from_start_to_end(cave_graph, false);
+
+        //println!("paths are {:?}", paths);
+        assert_eq!(paths.len(), 19);
+
+        let filename = "day12_test_3.txt";
+        let cave_graph = read_cave_graph_from_file(filename).unwrap();
+
+        let paths = get_all_paths_from_start_to_end(cave_graph, false);
+
+        //println!("paths are {:?}", paths);
+        assert_eq!(paths.len(), 226);
+    }
+
+    #[test]
+    fn should_get_all_paths_with_longer_time_to_visit_a_small_cave_twice_from_start_to_end_given_adjacency_matrix(
+    ) {
+        let filename = "day12_test.txt";
+        let cave_graph = read_cave_graph_from_file(filename).unwrap();
+
+        let paths = get_all_paths_from_start_to_end(cave_graph, true);
+
+        //println!("paths are {:?}", paths);
+        assert_eq!(paths.len(), 36);
+
+        let filename = "day12_test_2.txt";
+        let cave_graph = read_cave_graph_from_file(filename).unwrap();
+
+        let paths = get_all_paths_from_start_to_end(cave_graph, true);
+
+        //println!("paths are {:?}", paths);
+        assert_eq!(paths.len(), 103);
+
+        let filename = "day12_test_3.txt";
+        let cave_graph = read_cave_graph_from_file(filename).unwrap();
+
+        let paths = get_all_paths_from_start_to_end(cave_graph, true);
+
+        //println!("paths are {:?}", paths);
+        assert_eq!(paths.len(), 3509);
+    }
+
+    #[test]
+    fn should_get_paths_given_visit_policies() {
+        let filename = "day12_test.txt";
+        let cave_graph = read_cave_graph_from_file(filename).unwrap();
+        let count = |policy: &dyn VisitPolicy| get_paths_with_policy(&cave_graph, policy).len();
+
+        assert_eq!(count(&SmallCavesAtMost { times: 1 }), 10);
+        assert_eq!(count(&SmallCavesTwiceAtMost { caves: 1 }), 36);
+        assert_eq!(
+            count(&SmallCavesTwiceAtMost { caves: 2 }),
+            count(&SmallCavesAtMost { times: 2 })
+        );
+
+        let c = cave_graph.graph.node_id("c").unwrap();
+        let passing_c = PassingThrough {
+            cave: c,
+            policy: SmallCavesOnce,
+        };
+        assert_eq!(count(&passing_c), 5);
+
+        let short = MaxLength {
+            length: 3,
+            policy: SmallCavesOnce,
+        };
+        let paths = get_path_names(&cave_graph, get_paths_with_policy(&cave_graph, &short));
+        assert_eq!(paths.len(), 4);
+        assert!(paths.contains(&vec![
+            "start".to_string(),
+            "b".to_string(),
+            "A".to_string(),
+            "end".to_string()
+        ]));
+    }
+
+    #[test]
+    fn should_count_paths_without_building_them_given_adjacency_matrix() {
+        for (filename, once, twice) in [
+            ("day12_test.txt", 10, 36),
+            ("day12_test_2.txt", 19, 103),
+            ("day12_test_3.txt", 226, 3509),
+        ] {
+            let cave_graph = read_cave_graph_from_file(filename).unwrap();
+
+            assert_eq!(count_paths_from_start_to_end(&cave_graph, false), once);
+            assert_eq!(count_paths_from_start_to_end(&cave_graph, true), twice);
+        }
+    }
+
+    #[test]
+    fn should_get_paths_lazily_given_a_visit_policy() {
+        let filename = "day12_test_3.txt";
+        let cave_graph = read_cave_graph_from_file(filename).unwrap();
+
+        let mut paths = get_paths_iter_with_policy(&cave_graph, &OneSmallCaveTwice);
+        let first = paths.next().unwrap();
+
+        assert_eq!(first[0], cave_graph.start);
+        assert_eq!(first[first.len() - 1], cave_graph.end);
+        assert_eq!(paths.count(), 3508);
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_test_input_through_the_solution_trait() {
+        let input = std::fs::read_to_string("day12_test.txt").unwrap();
+        let parsed = Day12::parse(&input).unwrap();
+
+        assert_eq!(Day12::part1(&parsed).unwrap(), 10);
+        assert_eq!(Day12::part2(&parsed).unwrap(), 36);
+    }
+
+    #[test]
+    fn should_get_all_paths_given_the_example_input_as_a_str() {
+        let input = "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+";
+        let cave_graph = read_cave_graph_from_str(input).unwrap();
+
+        assert_eq!(cave_graph.graph.len(), 6);
+        assert_eq!(get_all_paths_from_start_to_end(cave_graph, false).len(), 10);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::CaveGraph;
+use common::NodeId;
+
+// Which caves a path may step into next. The start cave is never re-entered whatever the
+// policy says, and a path is finished as soon as it reaches the end cave.
+pub trait VisitPolicy {
+    fn can_visit(&self, cave_graph: &CaveGraph, path: &[NodeId], cave: NodeId) -> bool;
+
+    // checked once a path has reached the end cave
+    fn accepts(&self, _cave_graph: &CaveGraph, _path: &[NodeId]) -> bool {
+        true
+    }
+}
+
+fn get_visit_count(path: &[NodeId], cave: NodeId) -> usize {
+    path.iter().filter(|visited| **visited == cave).count()
+}
+
+fn get_small_caves_visited_twice(cave_graph: &CaveGraph, path: &[NodeId]) -> usize {
+    path.iter()
+        .enumerate()
+        .filter(|(index, visited)| {
+            cave_graph.is_small_cave(**visited) && path[index + 1..].contains(visited)
+        })
+        .count()
+}
+
+// Part 1: every small cave at most once.
+#[derive(Debug, Clone, Copy)]
+pub struct SmallCavesOnce;
+
+impl VisitPolicy for SmallCavesOnce {
+    fn can_visit(&self, cave_graph: &CaveGraph, path: &[NodeId], cave: NodeId) -> bool {
+        SmallCavesTwiceAtMost { caves: 0 }.can_visit(cave_graph, path, cave)
+    }
+}
+
+// Part 2: a single small cave twice, the others at most once.
+#[derive(Debug, Clone, Copy)]
+pub struct OneSmallCaveTwice;
+
+impl VisitPolicy for OneSmallCaveTwice {
+    fn can_visit(&self, cave_graph: &CaveGraph, path: &[NodeId], cave: NodeId) -> bool {
+        SmallCavesTwiceAtMost { caves: 1 }.can_visit(cave_graph, path, cave)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SmallCavesAtMost {
+    pub times: usize,
+}
+
+impl VisitPolicy for SmallCavesAtMost {
+    fn can_visit(&self, cave_graph: &CaveGraph, path: &[NodeId], cave: NodeId) -> bool {
+        !cave_graph.is_small_cave(cave) || get_visit_count(path, cave) < self.times
+    }
+}
+
+// Up to `caves` different small caves may be visited twice, the others at most once.
+#[derive(Debug, Clone, Copy)]
+pub struct SmallCavesTwiceAtMost {
+    pub caves: usize,
+}
+
+impl VisitPolicy for SmallCavesTwiceAtMost {
+    fn can_visit(&self, cave_graph: &CaveGraph, path: &[NodeId], cave: NodeId) -> bool {
+        if !cave_graph.is_small_cave(cave) {
+            return true;
+        }
+        match get_visit_count(path, cave) {
+            0 => true,
+            1 => get_small_caves_visited_twice(cave_graph, path) < self.caves,
+            _ => false,
+        }
+    }
+}
+
+// Keeps only the paths of `policy` that go through `cave`.
+#[derive(Debug, Clone, Copy)]
+pub struct PassingThrough<P> {
+    pub cave: NodeId,
+    pub policy: P,
+}
+
+impl<P: VisitPolicy> VisitPolicy for PassingThrough<P> {
+    fn can_visit(&self, cave_graph: &CaveGraph, path: &[NodeId], cave: NodeId) -> bool {
+        self.policy.can_visit(cave_graph, path, cave)
+    }
+
+    fn accepts(&self, cave_graph: &CaveGraph, path: &[NodeId]) -> bool {
+        path.contains(&self.cave) && self.policy.accepts(cave_graph, path)
+    }
+}
+
+// Limits the paths of `policy` to at most `length` moves between caves.
+#[derive(Debug, Clone, Copy)]
+pub struct MaxLength<P> {
+    pub length: usize,
+    pub policy: P,
+}
+
+impl<P: VisitPolicy> VisitPolicy for MaxLength<P> {
+    fn can_visit(&self, cave_graph: &CaveGraph, path: &[NodeId], cave: NodeId) -> bool {
+        path.len() <= self.length && self.policy.can_visit(cave_graph, path, cave)
+    }
+
+    fn accepts(&self, cave_graph: &CaveGraph, path: &[NodeId]) -> bool {
+        self.policy.accepts(cave_graph, path)
+    }
+}
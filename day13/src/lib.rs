@@ -0,0 +1,260 @@
+use common::{
+    input_lines, lines_from_str, parse_lines_from_file, parse_lines_from_reader,
+    parse_lines_from_str, Grid, ParseError, Solution, SolveError,
+};
+use std::{io::BufRead, path::Path};
+
+#[derive(Debug, Clone)]
+pub struct ThermalManual {
+    pub transparent_paper: Grid<i64>,
+    pub fold_instructions: Vec<(String, i64)>,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = ThermalManual;
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<ThermalManual, ParseError> {
+        parse_transparent_paper_by_line(&lines_from_str(input))
+    }
+
+    fn part1(manual: &ThermalManual) -> Result<i64, SolveError> {
+        if manual.fold_instructions.is_empty() {
+            return Ok(get_visible_dots_from_paper(
+                manual.transparent_paper.clone(),
+            ));
+        }
+        let new_manual = get_folded_paper_and_remaining_instructions(manual.clone());
+        Ok(get_visible_dots_from_paper(new_manual.transparent_paper))
+    }
+
+    fn part2(manual: &ThermalManual) -> Result<String, SolveError> {
+        let mut new_manual = manual.clone();
+        while !new_manual.fold_instructions.is_empty() {
+            new_manual = get_folded_paper_and_remaining_instructions(new_manual);
+        }
+        Ok(format!(
+            "\n{}",
+            get_paper_drawing(new_manual.transparent_paper)
+        ))
+    }
+}
+
+pub fn get_transparent_paper_from_file(
+    filename: impl AsRef<Path>,
+) -> Result<ThermalManual, ParseError> {
+    parse_lines_from_file(filename, parse_transparent_paper_by_line)
+}
+
+pub fn get_transparent_paper_from_str(input: &str) -> Result<ThermalManual, ParseError> {
+    parse_lines_from_str(input, parse_transparent_paper_by_line)
+}
+
+pub fn get_transparent_paper_from_reader(
+    reader: impl BufRead,
+) -> Result<ThermalManual, ParseError> {
+    parse_lines_from_reader(reader, parse_transparent_paper_by_line)
+}
+
+pub fn parse_transparent_paper_by_line(
+    lines_from_file: &[String],
+) -> Result<ThermalManual, ParseError> {
+    let mut x_max = 0;
+    let mut y_max = 0;
+    let mut init_list = vec![];
+    let mut index_fold = lines_from_file.len();
+    for (index, line) in input_lines(lines_from_file).enumerate() {
+        if line.text.trim().is_empty() {
+            index_fold = index;
+            break;
+        }
+        let mut splits = line.text.trim().split(',');
+        let x = line.expect_token(splits.next(), "an x coordinate")?;
+        let y = line.expect_token(splits.next(), "a y coordinate")?;
+        let x = line.parse_number::<u32>(x, "a non-negative x coordinate")? as i64;
+        let y = line.parse_number::<u32>(y, "a non-negative y coordinate")? as i64;
+        if x > x_max {
+            x_max = x;
+        }
+        if y > y_max {
+            y_max = y;
+        }
+        init_list.push((x, y));
+    }
+
+    if init_list.is_empty() {
+        return Err(ParseError::for_whole_input("", "at least one dot like x,y"));
+    }
+
+    let mut paper = Grid::new(x_max as usize + 1, y_max as usize + 1, 0);
+    for item in init_list {
+        paper[(item.0 as usize, item.1 as usize)] = 1;
+    }
+    //println!("the init transparent paper is {:?}", paper);
+
+    let mut instructions = vec![];
+    for line in input_lines(lines_from_file).skip(index_fold + 1) {
+        let fold = match line.text.trim().strip_prefix("fold along ") {
+            Some(fold) => fold,
+            None => return Err(line.error_at(line.text.trim(), "fold along x=<n> or y=<n>")),
+        };
+        let mut fold_split = fold.split('=');
+        let direction = line.expect_token(fold_split.next(), "x or y")?;
+        let pos_token = line.expect_token(fold_split.next(), "a fold position after =")?;
+        let limit = match direction {
+            "x" => x_max,
+            "y" => y_max,
+            _ => return Err(line.error_at(direction, "x or y")),
+        };
+        let pos = line.parse_number::<u32>(pos_token, "a non-negative fold position")? as i64;
+        if pos > limit {
+            return Err(line.error_at(pos_token, format!("a fold position up to {}", limit)));
+        }
+        instructions.push((direction.to_string(), pos));
+    }
+
+    Ok(ThermalManual {
+        transparent_paper: paper,
+        fold_instructions: instructions,
+    })
+}
+
+pub fn get_folded_paper_and_remaining_instructions(manual: ThermalManual) -> ThermalManual {
+    let (direction, pos) = manual.fold_instructions[0].clone();
+    let transparent_paper = match direction.as_str() {
+        // folding left along x is folding up along y on the transposed paper
+        "x" => fold_paper_up(&manual.transparent_paper.transpose(), pos as usize).transpose(),
+        _ => fold_paper_up(&manual.transparent_paper, pos as usize),
+    };
+
+    let fold_instructions = manual.fold_instructions[1..].to_vec();
+
+    ThermalManual {
+        transparent_paper,
+        fold_instructions,
+    }
+}
+
+fn fold_paper_up(paper: &Grid<i64>, pos: usize) -> Grid<i64> {
+    let width = paper.width();
+    let upper_part = paper.crop(0, 0, width, pos);
+    let lower_part = paper
+        .crop(0, pos + 1, width, paper.height())
+        .flip_vertical();
+
+    // both parts line up with the fold line, which is the bottom of the new paper
+    let new_height = upper_part.height().max(lower_part.height());
+    let mut transparent_paper = Grid::new(width, new_height, 0);
+    for part in [upper_part, lower_part] {
+        let offset = new_height - part.height();
+        for ((x, y), dot) in part.positions() {
+            transparent_paper[(x, y + offset)] |= dot;
+        }
+    }
+    transparent_paper
+}
+
+pub fn get_visible_dots_from_paper(paper: Grid<i64>) -> i64 {
+    paper.iter().filter(|dot| **dot > 0).count() as i64
+}
+
+pub fn get_paper_drawing(paper: Grid<i64>) -> String {
+    paper
+        .map(|dot| if *dot > 0 { '#' } else { '.' })
+        .to_string()
+}
+
+pub fn print_paper(paper: Grid<i64>) {
+    print!("{}", get_paper_drawing(paper));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_trasparent_paper_from_file_given_the_filename() {
+        let filename = "day13_test.txt";
+        let manual = get_transparent_paper_from_file(filename).unwrap();
+
+        assert_eq!(manual.transparent_paper.height(), 15);
+        assert_eq!(manual.transparent_paper.width(), 11);
+
+        assert_eq!(manual.fold_instructions.len(), 2);
+    }
+
+    #[test]
+    fn should_get_parse_error_with_position_given_a_bad_fold_instruction() {
+        let lines = vec![
+            "6,10".to_string(),
+            "".to_string(),
+            "fold along z=7".to_string(),
+        ];
+        let error = parse_transparent_paper_by_line(&lines).err().unwrap();
+
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 12);
+        assert_eq!(error.expected, "x or y");
+
+        let lines = vec!["6,10".to_string(), "".to_string(), "fold y=7".to_string()];
+        let error = parse_transparent_paper_by_line(&lines).err().unwrap();
+
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 1);
+    }
+
+    #[test]
+    fn should_get_new_folded_paper_given_the_initial_paper_and_fold_instructions() {
+        let filename = "day13_test.txt";
+        let manual = get_transparent_paper_from_file(filename).unwrap();
+
+        let new_manual = get_folded_paper_and_remaining_instructions(manual);
+
+        // println!(
+        //     "the new transparent paper is {:?}",
+        //     new_manual.transparent_paper
+        // );
+
+        assert_eq!(new_manual.transparent_paper.height(), 7);
+        assert_eq!(new_manual.transparent_paper.width(), 11);
+
+        assert_eq!(new_manual.fold_instructions.len(), 1);
+
+        assert_eq!(
+            get_visible_dots_from_paper(new_manual.clone().transparent_paper),
+            17
+        );
+
+        let new_manual = get_folded_paper_and_remaining_instructions(new_manual);
+
+        // println!(
+        //     "the new transparent paper is {:?}",
+        //     new_manual.transparent_paper
+        // );
+
+        assert_eq!(new_manual.transparent_paper.height(), 7);
+        assert_eq!(new_manual.transparent_paper.width(), 5);
+
+        assert_eq!(new_manual.fold_instructions.len(), 0);
+
+        assert_eq!(
+            get_visible_dots_from_paper(new_manual.clone().transparent_paper),
+            16
+        );
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_test_input_through_the_solution_trait() {
+        let input = std::fs::read_to_string("day13_test.txt").unwrap();
+        let manual = Day13::parse(&input).unwrap();
+
+        assert_eq!(Day13::part1(&manual).unwrap(), 17);
+        assert_eq!(
+            Day13::part2(&manual).unwrap(),
+            "\n#####\n#...#\n#...#\n#...#\n#####\n.....\n.....\n"
+        );
+    }
+}
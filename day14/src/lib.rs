@@ -0,0 +1,290 @@
+use common::{
+    input_lines, lines_from_str, parse_lines_from_file, parse_lines_from_reader,
+    parse_lines_from_str, ParseError, Solution, SolveError,
+};
+use std::{collections::HashMap, error::Error, fmt, io::BufRead, path::Path};
+
+#[derive(Debug, Clone)]
+pub struct PolymerManual {
+    pub template: String,
+    pub insertion_rules: HashMap<(char, char), char>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolymerOverflow {
+    pub step: usize,
+}
+
+impl fmt::Display for PolymerOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "error: the pair counts no longer fit into 128 bits after step {}",
+            self.step
+        )
+    }
+}
+
+impl Error for PolymerOverflow {}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = PolymerManual;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<PolymerManual, ParseError> {
+        parse_polymer_manual_by_line(&lines_from_str(input))
+    }
+
+    fn part1(manual: &PolymerManual) -> Result<u128, SolveError> {
+        let counts = get_element_counts_by_naive_insertion(manual, 10);
+        Ok(get_most_minus_least_common_element_count(&counts))
+    }
+
+    fn part2(manual: &PolymerManual) -> Result<u128, SolveError> {
+        let counts = get_element_counts_by_pair_counts(manual, 40)?;
+        Ok(get_most_minus_least_common_element_count(&counts))
+    }
+}
+
+pub fn read_polymer_manual_from_file(
+    filename: impl AsRef<Path>,
+) -> Result<PolymerManual, ParseError> {
+    parse_lines_from_file(filename, parse_polymer_manual_by_line)
+}
+
+pub fn read_polymer_manual_from_str(input: &str) -> Result<PolymerManual, ParseError> {
+    parse_lines_from_str(input, parse_polymer_manual_by_line)
+}
+
+pub fn read_polymer_manual_from_reader(reader: impl BufRead) -> Result<PolymerManual, ParseError> {
+    parse_lines_from_reader(reader, parse_polymer_manual_by_line)
+}
+
+pub fn parse_polymer_manual_by_line(lines: &[String]) -> Result<PolymerManual, ParseError> {
+    let mut lines = input_lines(lines);
+    let template_line = match lines.next() {
+        Some(line) => line,
+        None => return Err(ParseError::for_whole_input("", "a polymer template")),
+    };
+    let template = template_line.text.trim();
+    if template.is_empty() {
+        return Err(template_line.error_at_end("a polymer template"));
+    }
+    if let Some((index, c)) = template
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_uppercase())
+    {
+        return Err(
+            template_line.error_at(&template[index..index + c.len_utf8()], "an element A-Z")
+        );
+    }
+
+    let mut insertion_rules = HashMap::new();
+    for line in lines {
+        let rule = line.text.trim();
+        if rule.is_empty() {
+            continue;
+        }
+        let mut splits = rule.split("->");
+        let pair = line.expect_token(splits.next(), "a pair of elements")?;
+        let element = line.expect_token(splits.next(), "an element to insert after ->")?;
+        let pair_elements = pair.chars().collect::<Vec<char>>();
+        if pair_elements.len() != 2 || !pair_elements.iter().all(|c| c.is_ascii_uppercase()) {
+            return Err(line.error_at(pair, "a pair of elements like CH"));
+        }
+        let inserted = element.chars().collect::<Vec<char>>();
+        if inserted.len() != 1 || !inserted[0].is_ascii_uppercase() {
+            return Err(line.error_at(element, "a single element A-Z"));
+        }
+        if insertion_rules
+            .insert((pair_elements[0], pair_elements[1]), inserted[0])
+            .is_some()
+        {
+            return Err(line.error_at(pair, "each pair only once"));
+        }
+    }
+
+    Ok(PolymerManual {
+        template: template.to_string(),
+        insertion_rules,
+    })
+}
+
+pub fn get_polymer_by_naive_insertion(manual: &PolymerManual, steps: usize) -> String {
+    let mut polymer = manual.template.chars().collect::<Vec<char>>();
+    for _ in 0..steps {
+        let mut new_polymer = Vec::with_capacity(polymer.len() * 2);
+        for pair in polymer.windows(2) {
+            new_polymer.push(pair[0]);
+            if let Some(element) = manual.insertion_rules.get(&(pair[0], pair[1])) {
+                new_polymer.push(*element);
+            }
+        }
+        new_polymer.extend(polymer.last());
+        polymer = new_polymer;
+    }
+
+    polymer.into_iter().collect()
+}
+
+pub fn get_element_counts_by_naive_insertion(
+    manual: &PolymerManual,
+    steps: usize,
+) -> HashMap<char, u128> {
+    let mut counts = HashMap::new();
+    for element in get_polymer_by_naive_insertion(manual, steps).chars() {
+        *counts.entry(element).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn add_count<K: std::hash::Hash + Eq>(
+    counts: &mut HashMap<K, u128>,
+    key: K,
+    count: u128,
+    step: usize,
+) -> Result<(), PolymerOverflow> {
+    let total = counts.entry(key).or_insert(0);
+    *total = total.checked_add(count).ok_or(PolymerOverflow { step })?;
+    Ok(())
+}
+
+// Only the number of every pair matters: pair AB with rule AB -> C becomes AC and CB.
+pub fn get_pair_counts_after_steps(
+    manual: &PolymerManual,
+    steps: usize,
+) -> Result<HashMap<(char, char), u128>, PolymerOverflow> {
+    let template = manual.template.chars().collect::<Vec<char>>();
+    let mut pair_counts = HashMap::new();
+    for pair in template.windows(2) {
+        *pair_counts.entry((pair[0], pair[1])).or_insert(0) += 1;
+    }
+
+    for step in 1..=steps {
+        let mut new_pair_counts = HashMap::new();
+        for ((left, right), count) in pair_counts {
+            match manual.insertion_rules.get(&(left, right)) {
+                Some(&element) => {
+                    add_count(&mut new_pair_counts, (left, element), count, step)?;
+                    add_count(&mut new_pair_counts, (element, right), count, step)?;
+                }
+                None => add_count(&mut new_pair_counts, (left, right), count, step)?,
+            }
+        }
+        pair_counts = new_pair_counts;
+    }
+
+    Ok(pair_counts)
+}
+
+pub fn get_element_counts_by_pair_counts(
+    manual: &PolymerManual,
+    steps: usize,
+) -> Result<HashMap<char, u128>, PolymerOverflow> {
+    let mut counts = HashMap::new();
+    // every element starts a pair except the last one, which never changes
+    for ((left, _), count) in get_pair_counts_after_steps(manual, steps)? {
+        add_count(&mut counts, left, count, steps)?;
+    }
+    if let Some(last) = manual.template.chars().last() {
+        add_count(&mut counts, last, 1, steps)?;
+    }
+    Ok(counts)
+}
+
+pub fn get_most_minus_least_common_element_count(counts: &HashMap<char, u128>) -> u128 {
+    let most = counts.values().max().copied().unwrap_or(0);
+    let least = counts.values().min().copied().unwrap_or(0);
+    most - least
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_polymer_manual_from_file_given_the_filename() {
+        let filename = "day14_test.txt";
+        let manual = read_polymer_manual_from_file(filename).unwrap();
+
+        assert_eq!(manual.template, "NNCB");
+        assert_eq!(manual.insertion_rules.len(), 16);
+        assert_eq!(manual.insertion_rules[&('C', 'H')], 'B');
+    }
+
+    #[test]
+    fn should_get_parse_error_given_a_bad_insertion_rule() {
+        let lines = vec!["NNCB".to_string(), "".to_string(), "CHX -> B".to_string()];
+        let error = parse_polymer_manual_by_line(&lines).err().unwrap();
+
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 1);
+        assert_eq!(error.text, "CHX");
+
+        let lines = vec!["NNCB".to_string(), "".to_string(), "CH ->".to_string()];
+        let error = parse_polymer_manual_by_line(&lines).err().unwrap();
+
+        assert_eq!(error.expected, "an element to insert after ->");
+    }
+
+    #[test]
+    fn should_get_polymer_after_steps_given_the_template_and_rules() {
+        let filename = "day14_test.txt";
+        let manual = read_polymer_manual_from_file(filename).unwrap();
+
+        assert_eq!(get_polymer_by_naive_insertion(&manual, 1), "NCNBCHB");
+        assert_eq!(get_polymer_by_naive_insertion(&manual, 2), "NBCCNBBBCBHCB");
+        assert_eq!(get_polymer_by_naive_insertion(&manual, 10).len(), 3073);
+
+        let counts = get_element_counts_by_naive_insertion(&manual, 10);
+        assert_eq!(counts[&'B'], 1749);
+        assert_eq!(counts[&'H'], 161);
+        assert_eq!(get_most_minus_least_common_element_count(&counts), 1588);
+    }
+
+    #[test]
+    fn should_get_same_counts_by_pair_counts_as_by_naive_insertion() {
+        let filename = "day14_test.txt";
+        let manual = read_polymer_manual_from_file(filename).unwrap();
+
+        for steps in 0..=10 {
+            assert_eq!(
+                get_element_counts_by_pair_counts(&manual, steps).unwrap(),
+                get_element_counts_by_naive_insertion(&manual, steps)
+            );
+        }
+
+        let counts = get_element_counts_by_pair_counts(&manual, 40).unwrap();
+        assert_eq!(
+            get_most_minus_least_common_element_count(&counts),
+            2188189693529
+        );
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_test_input_through_the_solution_trait() {
+        let input = std::fs::read_to_string("day14_test.txt").unwrap();
+        let parsed = Day14::parse(&input).unwrap();
+
+        assert_eq!(Day14::part1(&parsed).unwrap(), 1588);
+        assert_eq!(Day14::part2(&parsed).unwrap(), 2188189693529);
+    }
+
+    #[test]
+    fn should_get_overflow_error_given_a_high_step_count() {
+        let filename = "day14_test.txt";
+        let manual = read_polymer_manual_from_file(filename).unwrap();
+
+        let counts = get_element_counts_by_pair_counts(&manual, 100).unwrap();
+        assert!(counts.values().sum::<u128>() > u64::MAX as u128);
+
+        let error = get_element_counts_by_pair_counts(&manual, 200).unwrap_err();
+        assert!(error.step > 100 && error.step < 130);
+        assert!(error
+            .to_string()
+            .contains(&format!("after step {}", error.step)));
+    }
+}
@@ -1,3 +1,22 @@
+use common::exit_on_error;
+use day14::{
+    get_element_counts_by_naive_insertion, get_element_counts_by_pair_counts,
+    get_most_minus_least_common_element_count, read_polymer_manual_from_file,
+};
+
 fn main() {
-    println!("Hello, world!");
+    let filename = "day14_input.txt";
+    let manual = exit_on_error(read_polymer_manual_from_file(filename));
+
+    let counts = get_element_counts_by_naive_insertion(&manual, 10);
+    println!(
+        "most minus least common element after 10 steps is {}",
+        get_most_minus_least_common_element_count(&counts)
+    );
+
+    let counts = exit_on_error(get_element_counts_by_pair_counts(&manual, 40));
+    println!(
+        "most minus least common element after 40 steps is {}",
+        get_most_minus_least_common_element_count(&counts)
+    );
 }
@@ -0,0 +1,254 @@
+use common::{
+    input_lines, lines_from_str, parse_lines_from_file, parse_lines_from_reader,
+    parse_lines_from_str, InputLine, ParseError, Solution, SolveError,
+};
+use std::{io::BufRead, path::Path};
+
+mod submarine;
+mod trajectory;
+
+pub use submarine::{
+    run_program, trace_program, AimModel, Back, Command, DepthModel, Down, Forward, Heading,
+    MovementModel, Program, Repeat, SubmarineState, Surface, Turn, TurnTo, Up,
+};
+pub use trajectory::{get_depth_profile_svg, get_trajectory_csv};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Program;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Program, ParseError> {
+        parse_program_by_line(&lines_from_str(input))
+    }
+
+    fn part1(program: &Program) -> Result<i64, SolveError> {
+        Ok(get_horizontal_position_times_depth(&run_program(
+            program,
+            &DepthModel,
+        )))
+    }
+
+    fn part2(program: &Program) -> Result<i64, SolveError> {
+        Ok(get_horizontal_position_times_depth(&run_program(
+            program, &AimModel,
+        )))
+    }
+}
+
+pub fn read_program_from_file_by_line(filename: impl AsRef<Path>) -> Result<Program, ParseError> {
+    parse_lines_from_file(filename, parse_program_by_line)
+}
+
+pub fn read_program_from_str_by_line(input: &str) -> Result<Program, ParseError> {
+    parse_lines_from_str(input, parse_program_by_line)
+}
+
+pub fn read_program_from_reader_by_line(reader: impl BufRead) -> Result<Program, ParseError> {
+    parse_lines_from_reader(reader, parse_program_by_line)
+}
+
+pub fn parse_program_by_line(lines: &[String]) -> Result<Program, ParseError> {
+    // every open `repeat` block: its line, how often it runs and the commands before it
+    let mut blocks: Vec<(InputLine, usize, Program)> = vec![];
+    let mut program: Program = vec![];
+    for line in input_lines(lines) {
+        //println!("{:?}", line);
+        let mut split = line.text.split_whitespace();
+        let name = line.expect_token(split.next(), "a command")?;
+        let command: Box<dyn Command> = match name {
+            "forward" => Box::new(Forward(parse_length(&line, split.next())?)),
+            "back" => Box::new(Back(parse_length(&line, split.next())?)),
+            "up" => Box::new(Up(parse_length(&line, split.next())?)),
+            "down" => Box::new(Down(parse_length(&line, split.next())?)),
+            "surface" => Box::new(Surface),
+            "turn" => {
+                let turn = line.expect_token(split.next(), "left or right")?;
+                match turn {
+                    "left" => Box::new(TurnTo(Turn::Left)),
+                    "right" => Box::new(TurnTo(Turn::Right)),
+                    _ => return Err(line.error_at(turn, "left or right")),
+                }
+            }
+            "repeat" => {
+                let times = line.expect_token(split.next(), "a repeat count")?;
+                let times = line.parse_number::<usize>(times, "a repeat count")?;
+                let brace = line.expect_token(split.next(), "`{`")?;
+                if brace != "{" {
+                    return Err(line.error_at(brace, "`{`"));
+                }
+                expect_end_of_line(&line, split.next())?;
+                blocks.push((line, times, std::mem::take(&mut program)));
+                continue;
+            }
+            "}" => {
+                let (_, times, outer_program) = match blocks.pop() {
+                    Some(block) => block,
+                    None => return Err(line.error_at(name, "a command, no repeat block is open")),
+                };
+                expect_end_of_line(&line, split.next())?;
+                let body = std::mem::replace(&mut program, outer_program);
+                program.push(Box::new(Repeat { times, body }));
+                continue;
+            }
+            _ => {
+                return Err(line.error_at(
+                    name,
+                    "one of forward, up, down, back, surface, turn or repeat",
+                ))
+            }
+        };
+        expect_end_of_line(&line, split.next())?;
+        program.push(command);
+    }
+
+    if let Some((line, _, _)) = blocks.last() {
+        return Err(line.error_at_end("a `}` closing this repeat block"));
+    }
+    Ok(program)
+}
+
+fn parse_length(line: &InputLine, token: Option<&str>) -> Result<i64, ParseError> {
+    let length = line.expect_token(token, "a length")?;
+    line.parse_number::<i64>(length, "a number")
+}
+
+fn expect_end_of_line(line: &InputLine, token: Option<&str>) -> Result<(), ParseError> {
+    match token {
+        Some(extra) => Err(line.error_at(extra, "end of line")),
+        None => Ok(()),
+    }
+}
+
+pub fn get_horizontal_position_times_depth(state: &SubmarineState) -> i64 {
+    state.position.0 * state.depth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // TODO: is this an integration test?
+    fn should_get_all_actions_from_file_by_line_given_the_filename() {
+        let filename = "day2_test.txt";
+        let actions = read_program_from_file_by_line(filename).unwrap();
+
+        assert_eq!(actions.len(), 6);
+    }
+
+    #[test]
+    fn should_get_parse_error_with_position_given_an_unknown_direction() {
+        let lines = vec!["forward 5".to_string(), "sideways 3".to_string()];
+        let error = parse_program_by_line(&lines).err().unwrap();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 1);
+        assert_eq!(error.text, "sideways");
+
+        let lines = vec!["down".to_string()];
+        let error = parse_program_by_line(&lines).err().unwrap();
+
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 5);
+        assert_eq!(error.expected, "a length");
+    }
+
+    fn get_test_program() -> Program {
+        vec![
+            Box::new(Forward(5)),
+            Box::new(Down(5)),
+            Box::new(Forward(8)),
+            Box::new(Up(3)),
+            Box::new(Down(8)),
+            Box::new(Forward(2)),
+        ]
+    }
+
+    #[test]
+    fn should_get_right_final_position_given_the_diving_action_consequences() {
+        let state = run_program(&get_test_program(), &DepthModel);
+
+        assert_eq!(state.position, (15, 0));
+        assert_eq!(get_horizontal_position_times_depth(&state), 150);
+    }
+
+    #[test]
+    fn should_get_right_final_position_given_the_diving_action_consequences_and_aim() {
+        let state = run_program(&get_test_program(), &AimModel);
+
+        assert_eq!(state.aim, 10);
+        assert_eq!(get_horizontal_position_times_depth(&state), 900);
+    }
+
+    #[test]
+    fn should_run_new_commands_and_repeat_blocks_given_a_program() {
+        let input = "\
+repeat 2 {
+  forward 3
+  down 2
+  repeat 3 {
+    back 1
+  }
+}
+turn left
+forward 4
+surface
+";
+        let program = read_program_from_str_by_line(input).unwrap();
+        assert_eq!(program.len(), 4);
+
+        let state = run_program(&program, &AimModel);
+        assert_eq!(state.position, (0, 4));
+        assert_eq!(state.heading, Some(Heading::North));
+        assert_eq!((state.depth, state.aim), (0, 0));
+
+        let states = trace_program(&program, &DepthModel);
+        assert_eq!(states.len(), 1 + 2 * 5 + 3);
+        assert_eq!(states[0], SubmarineState::default());
+        assert_eq!(states[states.len() - 2].depth, 4);
+        assert_eq!(states[states.len() - 1].depth, 0);
+
+        let error = parse_program_by_line(&["repeat 2 {".to_string()]).unwrap_err();
+        assert_eq!(error.expected, "a `}` closing this repeat block");
+    }
+
+    #[test]
+    fn should_export_trajectory_as_csv_and_svg_given_both_models() {
+        let states = trace_program(&get_test_program(), &DepthModel);
+        let csv = get_trajectory_csv(&states);
+        let rows = csv.lines().collect::<Vec<&str>>();
+
+        assert_eq!(rows.len(), 8);
+        assert_eq!(rows[0], "step,x,y,depth,aim,heading");
+        assert_eq!(rows[7], "6,15,0,10,0,");
+
+        let states = trace_program(&get_test_program(), &AimModel);
+        assert!(get_trajectory_csv(&states).ends_with("6,15,0,60,10,\n"));
+
+        let svg = get_depth_profile_svg(&states, "aim <model>");
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains("aim &lt;model&gt;"));
+        assert!(!svg.contains("href"));
+        let points = svg
+            .split("points=\"")
+            .nth(1)
+            .unwrap()
+            .split('"')
+            .next()
+            .unwrap();
+        assert_eq!(points.split(' ').count(), 7);
+        assert!(points.ends_with("750.0,350.0"));
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_test_input_through_the_solution_trait() {
+        let input = std::fs::read_to_string("day2_test.txt").unwrap();
+        let parsed = Day2::parse(&input).unwrap();
+
+        assert_eq!(Day2::part1(&parsed).unwrap(), 150);
+        assert_eq!(Day2::part2(&parsed).unwrap(), 900);
+    }
+}
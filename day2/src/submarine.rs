@@ -0,0 +1,245 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+// `position` is (x, y) on the surface plane; without a heading the submarine keeps
+// moving along x, which is all the original puzzle needs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SubmarineState {
+    pub position: (i64, i64),
+    pub depth: i64,
+    pub aim: i64,
+    pub heading: Option<Heading>,
+}
+
+impl SubmarineState {
+    pub fn move_ahead(&mut self, length: i64) {
+        let (x, y) = &mut self.position;
+        match self.heading.unwrap_or(Heading::East) {
+            Heading::East => *x += length,
+            Heading::West => *x -= length,
+            Heading::North => *y += length,
+            Heading::South => *y -= length,
+        }
+    }
+
+    pub fn turn(&mut self, turn: Turn) {
+        let headings = [Heading::North, Heading::East, Heading::South, Heading::West];
+        let current = self.heading.unwrap_or(Heading::East);
+        let index = headings.iter().position(|h| *h == current).unwrap_or(1);
+        self.heading = Some(match turn {
+            Turn::Right => headings[(index + 1) % 4],
+            Turn::Left => headings[(index + 3) % 4],
+        });
+    }
+}
+
+// How the basic movements change the state; part 1 and part 2 are two models of the same program.
+pub trait MovementModel {
+    fn forward(&self, state: &mut SubmarineState, length: i64);
+    fn up(&self, state: &mut SubmarineState, length: i64);
+    fn down(&self, state: &mut SubmarineState, length: i64);
+
+    fn surface(&self, state: &mut SubmarineState) {
+        state.depth = 0;
+    }
+}
+
+// Part 1: up and down change the depth directly.
+#[derive(Debug, Clone, Copy)]
+pub struct DepthModel;
+
+impl MovementModel for DepthModel {
+    fn forward(&self, state: &mut SubmarineState, length: i64) {
+        state.move_ahead(length);
+    }
+
+    fn up(&self, state: &mut SubmarineState, length: i64) {
+        state.depth -= length;
+    }
+
+    fn down(&self, state: &mut SubmarineState, length: i64) {
+        state.depth += length;
+    }
+}
+
+// Part 2: up and down change the aim, and moving forward dives along it.
+#[derive(Debug, Clone, Copy)]
+pub struct AimModel;
+
+impl MovementModel for AimModel {
+    fn forward(&self, state: &mut SubmarineState, length: i64) {
+        state.move_ahead(length);
+        state.depth += state.aim * length;
+    }
+
+    fn up(&self, state: &mut SubmarineState, length: i64) {
+        state.aim -= length;
+    }
+
+    fn down(&self, state: &mut SubmarineState, length: i64) {
+        state.aim += length;
+    }
+
+    fn surface(&self, state: &mut SubmarineState) {
+        state.depth = 0;
+        state.aim = 0;
+    }
+}
+
+// One instruction of a submarine program. `on_step` is called with the state after every
+// movement, which is what the trace mode collects.
+pub trait Command: fmt::Debug {
+    fn execute(
+        &self,
+        model: &dyn MovementModel,
+        state: &mut SubmarineState,
+        on_step: &mut dyn FnMut(&SubmarineState),
+    );
+}
+
+pub type Program = Vec<Box<dyn Command>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Forward(pub i64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Back(pub i64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Up(pub i64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Down(pub i64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Surface;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnTo(pub Turn);
+
+#[derive(Debug)]
+pub struct Repeat {
+    pub times: usize,
+    pub body: Program,
+}
+
+impl Command for Forward {
+    fn execute(
+        &self,
+        model: &dyn MovementModel,
+        state: &mut SubmarineState,
+        on_step: &mut dyn FnMut(&SubmarineState),
+    ) {
+        model.forward(state, self.0);
+        on_step(state);
+    }
+}
+
+impl Command for Back {
+    fn execute(
+        &self,
+        model: &dyn MovementModel,
+        state: &mut SubmarineState,
+        on_step: &mut dyn FnMut(&SubmarineState),
+    ) {
+        model.forward(state, -self.0);
+        on_step(state);
+    }
+}
+
+impl Command for Up {
+    fn execute(
+        &self,
+        model: &dyn MovementModel,
+        state: &mut SubmarineState,
+        on_step: &mut dyn FnMut(&SubmarineState),
+    ) {
+        model.up(state, self.0);
+        on_step(state);
+    }
+}
+
+impl Command for Down {
+    fn execute(
+        &self,
+        model: &dyn MovementModel,
+        state: &mut SubmarineState,
+        on_step: &mut dyn FnMut(&SubmarineState),
+    ) {
+        model.down(state, self.0);
+        on_step(state);
+    }
+}
+
+impl Command for Surface {
+    fn execute(
+        &self,
+        model: &dyn MovementModel,
+        state: &mut SubmarineState,
+        on_step: &mut dyn FnMut(&SubmarineState),
+    ) {
+        model.surface(state);
+        on_step(state);
+    }
+}
+
+impl Command for TurnTo {
+    fn execute(
+        &self,
+        _model: &dyn MovementModel,
+        state: &mut SubmarineState,
+        on_step: &mut dyn FnMut(&SubmarineState),
+    ) {
+        state.turn(self.0);
+        on_step(state);
+    }
+}
+
+impl Command for Repeat {
+    fn execute(
+        &self,
+        model: &dyn MovementModel,
+        state: &mut SubmarineState,
+        on_step: &mut dyn FnMut(&SubmarineState),
+    ) {
+        for _ in 0..self.times {
+            for command in &self.body {
+                command.execute(model, state, on_step);
+            }
+        }
+    }
+}
+
+pub fn run_program(program: &[Box<dyn Command>], model: &dyn MovementModel) -> SubmarineState {
+    let mut state = SubmarineState::default();
+    for command in program {
+        command.execute(model, &mut state, &mut |_| {});
+    }
+    state
+}
+
+// The starting state followed by the state after every movement.
+pub fn trace_program(
+    program: &[Box<dyn Command>],
+    model: &dyn MovementModel,
+) -> Vec<SubmarineState> {
+    let mut state = SubmarineState::default();
+    let mut states = vec![state];
+    for command in program {
+        command.execute(model, &mut state, &mut |step| states.push(*step));
+    }
+    states
+}
@@ -0,0 +1,111 @@
+use crate::{Heading, SubmarineState};
+
+const SVG_WIDTH: f64 = 800.0;
+const SVG_HEIGHT: f64 = 400.0;
+const SVG_MARGIN: f64 = 50.0;
+
+fn get_heading_name(heading: Option<Heading>) -> &'static str {
+    match heading {
+        None => "",
+        Some(Heading::North) => "north",
+        Some(Heading::East) => "east",
+        Some(Heading::South) => "south",
+        Some(Heading::West) => "west",
+    }
+}
+
+// One row per state of a trace, the starting state being step 0.
+pub fn get_trajectory_csv(states: &[SubmarineState]) -> String {
+    let mut csv = String::from("step,x,y,depth,aim,heading\n");
+    for (step, state) in states.iter().enumerate() {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            step,
+            state.position.0,
+            state.position.1,
+            state.depth,
+            state.aim,
+            get_heading_name(state.heading)
+        ));
+    }
+    csv
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Depth against step, deeper further down like the sea; no scripts, fonts or links to fetch.
+pub fn get_depth_profile_svg(states: &[SubmarineState], title: &str) -> String {
+    let steps = states.len().saturating_sub(1).max(1) as f64;
+    let min_depth = states.iter().map(|s| s.depth).min().unwrap_or(0).min(0);
+    let max_depth = states
+        .iter()
+        .map(|s| s.depth)
+        .max()
+        .unwrap_or(0)
+        .max(min_depth + 1);
+    let plot_width = SVG_WIDTH - 2.0 * SVG_MARGIN;
+    let plot_height = SVG_HEIGHT - 2.0 * SVG_MARGIN;
+    let x_of = |step: usize| SVG_MARGIN + step as f64 / steps * plot_width;
+    let y_of = |depth: i64| {
+        SVG_MARGIN + (depth - min_depth) as f64 / (max_depth - min_depth) as f64 * plot_height
+    };
+
+    let points = states
+        .iter()
+        .enumerate()
+        .map(|(step, state)| format!("{:.1},{:.1}", x_of(step), y_of(state.depth)))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n",
+        w = SVG_WIDTH,
+        h = SVG_HEIGHT
+    );
+    svg.push_str(&format!(
+        "  <rect width=\"{}\" height=\"{}\" fill=\"#f4f9fc\"/>\n",
+        SVG_WIDTH, SVG_HEIGHT
+    ));
+    svg.push_str(&format!(
+        "  <text x=\"{}\" y=\"30\" font-family=\"sans-serif\" font-size=\"16\">{}</text>\n",
+        SVG_MARGIN,
+        escape_xml(title)
+    ));
+    // the surface, then the depth axis on the left
+    svg.push_str(&format!(
+        "  <line x1=\"{m}\" y1=\"{y}\" x2=\"{r}\" y2=\"{y}\" stroke=\"#6aa7c8\"/>\n",
+        m = SVG_MARGIN,
+        r = SVG_WIDTH - SVG_MARGIN,
+        y = y_of(0)
+    ));
+    svg.push_str(&format!(
+        "  <line x1=\"{m}\" y1=\"{m}\" x2=\"{m}\" y2=\"{b}\" stroke=\"#333\"/>\n",
+        m = SVG_MARGIN,
+        b = SVG_HEIGHT - SVG_MARGIN
+    ));
+    for (depth, y) in [(min_depth, y_of(min_depth)), (max_depth, y_of(max_depth))] {
+        svg.push_str(&format!(
+            "  <text x=\"{}\" y=\"{:.1}\" font-family=\"sans-serif\" font-size=\"10\" text-anchor=\"end\">{}</text>\n",
+            SVG_MARGIN - 4.0,
+            y,
+            depth
+        ));
+    }
+    svg.push_str(&format!(
+        "  <text x=\"{}\" y=\"{}\" font-family=\"sans-serif\" font-size=\"10\" text-anchor=\"end\">step {}</text>\n",
+        SVG_WIDTH - SVG_MARGIN,
+        SVG_HEIGHT - SVG_MARGIN + 16.0,
+        states.len().saturating_sub(1)
+    ));
+    svg.push_str(&format!(
+        "  <polyline fill=\"none\" stroke=\"#0b3d91\" stroke-width=\"1.5\" points=\"{}\"/>\n",
+        points
+    ));
+    svg.push_str("</svg>\n");
+    svg
+}
@@ -0,0 +1,90 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitPreference {
+    MostCommon,
+    LeastCommon,
+}
+
+// What to keep when a column has as many 0s as 1s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieRule {
+    Keep(u8),
+    // neither value is strictly more or less common, so nothing is eliminated
+    KeepBoth,
+    // the value kept at the last column that decided anything, `otherwise` before that
+    PreviousWinner { otherwise: u8 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    MsbFirst,
+    LsbFirst,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitCriteria {
+    pub preference: BitPreference,
+    pub tie: TieRule,
+    pub order: BitOrder,
+}
+
+impl BitCriteria {
+    // The puzzle rules: most common value with ties to 1 for oxygen, least common with
+    // ties to 0 for CO2, both from the leftmost bit.
+    pub const OXYGEN_GENERATOR: BitCriteria = BitCriteria {
+        preference: BitPreference::MostCommon,
+        tie: TieRule::Keep(1),
+        order: BitOrder::MsbFirst,
+    };
+    pub const CO2_SCRUBBER: BitCriteria = BitCriteria {
+        preference: BitPreference::LeastCommon,
+        tie: TieRule::Keep(0),
+        order: BitOrder::MsbFirst,
+    };
+
+    // The value to keep given the counts of the column, or None to keep every reading.
+    pub fn get_kept_bit(&self, zeros: usize, ones: usize, previous: Option<u8>) -> Option<u8> {
+        if zeros == ones {
+            return match self.tie {
+                TieRule::Keep(bit) => Some(bit),
+                TieRule::KeepBoth => None,
+                TieRule::PreviousWinner { otherwise } => Some(previous.unwrap_or(otherwise)),
+            };
+        }
+        let ones_are_more_common = ones > zeros;
+        match (self.preference, ones_are_more_common) {
+            (BitPreference::MostCommon, true) | (BitPreference::LeastCommon, false) => Some(1),
+            _ => Some(0),
+        }
+    }
+
+    // The order readings are sorted in for filtering: the columns in the order they are
+    // looked at, first column as the highest bit.
+    pub fn get_sort_key(&self, reading: u128, width: usize) -> u128 {
+        match self.order {
+            BitOrder::MsbFirst => reading,
+            BitOrder::LsbFirst => reading.reverse_bits() >> (u128::BITS as usize - width),
+        }
+    }
+
+    // the column looked at in `step`, counted from the left like the digits of a line
+    pub fn get_column(&self, step: usize, width: usize) -> usize {
+        match self.order {
+            BitOrder::MsbFirst => step,
+            BitOrder::LsbFirst => width - 1 - step,
+        }
+    }
+}
+
+// The readings dropped at one column of a filtering run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitElimination {
+    pub column: usize,
+    pub kept: Option<u8>,
+    pub eliminated: Vec<u128>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterReport {
+    pub reading: u128,
+    pub eliminations: Vec<BitElimination>,
+}
@@ -0,0 +1,383 @@
+use common::{
+    input_lines, lines_from_str, parse_lines_from_file, parse_lines_from_reader,
+    parse_lines_from_str, BigUint, ParseError, Solution, SolveError,
+};
+use std::{io::BufRead, ops::Range, path::Path};
+
+mod bit_criteria;
+
+pub use bit_criteria::{
+    BitCriteria, BitElimination, BitOrder, BitPreference, FilterReport, TieRule,
+};
+
+pub const MAX_REPORT_WIDTH: usize = 128;
+
+// Every reading packed into one integer, the first digit of a line being the highest bit.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct DiagnosticReport {
+    pub width: usize,
+    pub readings: Vec<u128>,
+}
+
+impl DiagnosticReport {
+    pub fn len(&self) -> usize {
+        self.readings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.readings.is_empty()
+    }
+
+    // bit mask of the column `index`, counted from the left like the digits of a line
+    pub fn column_mask(&self, index: usize) -> u128 {
+        1 << (self.width - 1 - index)
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = DiagnosticReport;
+    type Answer1 = BigUint;
+    type Answer2 = BigUint;
+
+    fn parse(input: &str) -> Result<DiagnosticReport, ParseError> {
+        parse_power_consumption_binary_by_line(&lines_from_str(input))
+    }
+
+    fn part1(report: &DiagnosticReport) -> Result<BigUint, SolveError> {
+        let (gamma_rate, epsilon_rate) = get_power_consumption(report);
+        Ok(get_rating_product(gamma_rate, epsilon_rate))
+    }
+
+    fn part2(report: &DiagnosticReport) -> Result<BigUint, SolveError> {
+        let (oxygen_generator_rating, co2_scrubber_rating) =
+            get_life_support_rating(&mut report.clone());
+        Ok(get_rating_product(
+            oxygen_generator_rating,
+            co2_scrubber_rating,
+        ))
+    }
+}
+
+pub fn read_power_consumption_binary_from_file_by_line(
+    filename: impl AsRef<Path>,
+) -> Result<DiagnosticReport, ParseError> {
+    parse_lines_from_file(filename, parse_power_consumption_binary_by_line)
+}
+
+pub fn read_power_consumption_binary_from_str_by_line(
+    input: &str,
+) -> Result<DiagnosticReport, ParseError> {
+    parse_lines_from_str(input, parse_power_consumption_binary_by_line)
+}
+
+pub fn read_power_consumption_binary_from_reader_by_line(
+    reader: impl BufRead,
+) -> Result<DiagnosticReport, ParseError> {
+    parse_lines_from_reader(reader, parse_power_consumption_binary_by_line)
+}
+
+pub fn parse_power_consumption_binary_by_line(
+    lines: &[String],
+) -> Result<DiagnosticReport, ParseError> {
+    let mut report = DiagnosticReport::default();
+    for line in input_lines(lines) {
+        let bits = line.text.trim();
+        if report.is_empty() {
+            if bits.is_empty() || bits.len() > MAX_REPORT_WIDTH {
+                return Err(line.error_at(bits, format!("1 to {} binary digits", MAX_REPORT_WIDTH)));
+            }
+            report.width = bits.len();
+        } else if bits.len() != report.width {
+            return Err(line.error_at(
+                bits,
+                format!("{} digits as in the first line", report.width),
+            ));
+        }
+
+        let mut reading = 0;
+        for (index, c) in bits.char_indices() {
+            let bit = match c {
+                '0' => 0,
+                '1' => 1,
+                _ => {
+                    return Err(line.error_at(&bits[index..index + c.len_utf8()], "a binary digit"))
+                }
+            };
+            reading = reading << 1 | bit;
+        }
+        report.readings.push(reading);
+    }
+    if report.is_empty() {
+        return Err(ParseError::for_whole_input(
+            "",
+            "at least one line of digits",
+        ));
+    }
+    Ok(report)
+}
+
+// How many readings have a 1 in every column, from the leftmost column on.
+pub fn get_column_popcounts(report: &DiagnosticReport) -> Vec<usize> {
+    let mut popcounts = vec![0; report.width];
+    for reading in &report.readings {
+        let mut bits = *reading;
+        while bits != 0 {
+            let bit = bits.trailing_zeros() as usize;
+            popcounts[report.width - 1 - bit] += 1;
+            bits &= bits - 1;
+        }
+    }
+    popcounts
+}
+
+pub fn get_power_consumption(report: &DiagnosticReport) -> (u128, u128) {
+    let mut gamma_rate = 0;
+    for (index, ones) in get_column_popcounts(report).into_iter().enumerate() {
+        if ones * 2 > report.len() {
+            gamma_rate |= report.column_mask(index);
+        }
+    }
+    let all_columns = u128::MAX >> (MAX_REPORT_WIDTH - report.width);
+    let epsilon_rate = !gamma_rate & all_columns;
+
+    (gamma_rate, epsilon_rate)
+}
+
+// Sorts the readings in place once; both ratings are then found by binary partitioning.
+pub fn get_life_support_rating(report: &mut DiagnosticReport) -> (u128, u128) {
+    get_life_support_rating_with_criteria(
+        report,
+        &BitCriteria::OXYGEN_GENERATOR,
+        &BitCriteria::CO2_SCRUBBER,
+    )
+}
+
+pub fn get_life_support_rating_with_criteria(
+    report: &mut DiagnosticReport,
+    oxygen_generator_criteria: &BitCriteria,
+    co2_scrubber_criteria: &BitCriteria,
+) -> (u128, u128) {
+    sort_readings_for_criteria(report, oxygen_generator_criteria);
+    let oxygen_generator_rating = get_final_reading(report, oxygen_generator_criteria);
+    if co2_scrubber_criteria.order != oxygen_generator_criteria.order {
+        sort_readings_for_criteria(report, co2_scrubber_criteria);
+    }
+    let co2_scrubber_rating = get_final_reading(report, co2_scrubber_criteria);
+
+    (oxygen_generator_rating, co2_scrubber_rating)
+}
+
+pub fn sort_readings_for_criteria(report: &mut DiagnosticReport, criteria: &BitCriteria) {
+    let width = report.width;
+    report
+        .readings
+        .sort_unstable_by_key(|reading| criteria.get_sort_key(*reading, width));
+}
+
+pub fn get_final_reading(report: &DiagnosticReport, criteria: &BitCriteria) -> u128 {
+    get_final_reading_report(report, criteria).reading
+}
+
+// The readings have to be sorted for the criteria. Those still in the running that agree on
+// all columns looked at so far are a contiguous range with the 0s before the 1s; a column
+// where both values are kept splits every range in two. When several readings survive every
+// column, the one sorted first is returned.
+pub fn get_final_reading_report(report: &DiagnosticReport, criteria: &BitCriteria) -> FilterReport {
+    let readings = &report.readings;
+    let key_of = |reading: &u128| criteria.get_sort_key(*reading, report.width);
+    let mut ranges: Vec<Range<usize>> = std::iter::once(0..readings.len()).collect();
+    let mut previous = None;
+    let mut eliminations = vec![];
+    for step in 0..report.width {
+        if ranges.iter().map(|range| range.len()).sum::<usize>() <= 1 {
+            break;
+        }
+        let mask = 1 << (report.width - 1 - step);
+        let first_ones = ranges
+            .iter()
+            .map(|range| {
+                range.start
+                    + readings[range.clone()].partition_point(|reading| key_of(reading) & mask == 0)
+            })
+            .collect::<Vec<usize>>();
+        let zeros = ranges
+            .iter()
+            .zip(&first_ones)
+            .map(|(range, first_one)| first_one - range.start)
+            .sum::<usize>();
+        let ones = ranges
+            .iter()
+            .zip(&first_ones)
+            .map(|(range, first_one)| range.end - first_one)
+            .sum::<usize>();
+
+        // never filter every reading out, e.g. when the whole column is the same
+        let kept = match criteria.get_kept_bit(zeros, ones, previous) {
+            Some(1) if ones == 0 => Some(0),
+            Some(0) if zeros == 0 => Some(1),
+            kept => kept,
+        };
+        let mut eliminated = vec![];
+        let mut kept_ranges = vec![];
+        for (range, first_one) in ranges.into_iter().zip(first_ones) {
+            let (zero_range, one_range) = (range.start..first_one, first_one..range.end);
+            match kept {
+                Some(1) => {
+                    eliminated.extend_from_slice(&readings[zero_range]);
+                    kept_ranges.push(one_range);
+                }
+                Some(_) => {
+                    eliminated.extend_from_slice(&readings[one_range]);
+                    kept_ranges.push(zero_range);
+                }
+                None => kept_ranges.extend([zero_range, one_range]),
+            }
+        }
+        ranges = kept_ranges
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .collect();
+        if kept.is_some() {
+            previous = kept;
+        }
+        eliminations.push(BitElimination {
+            column: criteria.get_column(step, report.width),
+            kept,
+            eliminated,
+        });
+    }
+    FilterReport {
+        reading: readings[ranges.first().map_or(0, |range| range.start)],
+        eliminations,
+    }
+}
+
+// The product of two ratings, exact even when it does not fit into 128 bits.
+pub fn get_rating_product(a: u128, b: u128) -> BigUint {
+    BigUint::from(a).mul(&BigUint::from(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // TODO: is this an integration test?
+    fn should_get_all_actions_from_file_by_line_given_the_filename() {
+        let filename = "day3_test.txt";
+        let readings = read_power_consumption_binary_from_file_by_line(filename).unwrap();
+
+        assert_eq!(readings.len(), 12);
+    }
+
+    #[test]
+    fn should_get_right_power_consumption_given_the_diagnostic_report_in_binary() {
+        let filename = "day3_test.txt";
+        let readings = read_power_consumption_binary_from_file_by_line(filename).unwrap();
+
+        let (gamma_rate, epsilon_rate) = get_power_consumption(&readings);
+        assert_eq!((gamma_rate, epsilon_rate), (22, 9));
+        assert_eq!(gamma_rate * epsilon_rate, 198);
+    }
+
+    #[test]
+    fn should_get_right_life_support_rating_given_the_diagnostic_report_in_binary() {
+        let filename = "day3_test.txt";
+        let readings = read_power_consumption_binary_from_file_by_line(filename).unwrap();
+
+        let mut readings = readings;
+        let (oxygen_generator_rating, co2_scrubber_rating) = get_life_support_rating(&mut readings);
+        assert_eq!(oxygen_generator_rating * co2_scrubber_rating, 230);
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_test_input_through_the_solution_trait() {
+        let input = std::fs::read_to_string("day3_test.txt").unwrap();
+        let parsed = Day3::parse(&input).unwrap();
+
+        assert_eq!(Day3::part1(&parsed).unwrap(), BigUint::from(198));
+        assert_eq!(Day3::part2(&parsed).unwrap(), BigUint::from(230));
+    }
+
+    #[test]
+    fn should_get_exact_ratings_given_a_report_wider_than_64_bits() {
+        let lines = vec![
+            "1".repeat(100) + "0",
+            "0".repeat(100) + "1",
+            "1".repeat(101),
+        ];
+        let mut report = parse_power_consumption_binary_by_line(&lines).unwrap();
+
+        assert_eq!(report.width, 101);
+        assert_eq!(get_column_popcounts(&report)[..2], [2, 2]);
+        assert_eq!(get_power_consumption(&report), ((1 << 101) - 1, 0));
+        assert_eq!(get_life_support_rating(&mut report), ((1 << 101) - 1, 1));
+        assert_eq!(
+            get_rating_product(u128::MAX, u128::MAX).to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+
+        let error = parse_power_consumption_binary_by_line(&["1".repeat(129)]).unwrap_err();
+        assert_eq!(error.expected, "1 to 128 binary digits");
+    }
+
+    #[test]
+    fn should_report_eliminated_readings_given_the_puzzle_criteria() {
+        let filename = "day3_test.txt";
+        let mut report = read_power_consumption_binary_from_file_by_line(filename).unwrap();
+        sort_readings_for_criteria(&mut report, &BitCriteria::OXYGEN_GENERATOR);
+
+        let filtered = get_final_reading_report(&report, &BitCriteria::OXYGEN_GENERATOR);
+        assert_eq!(filtered.reading, 0b10111);
+        assert_eq!(filtered.eliminations.len(), 5);
+        assert_eq!(filtered.eliminations[0].column, 0);
+        assert_eq!(filtered.eliminations[0].kept, Some(1));
+        assert_eq!(filtered.eliminations[0].eliminated.len(), 5);
+        assert_eq!(filtered.eliminations[4].eliminated, vec![0b10110]);
+
+        let filtered = get_final_reading_report(&report, &BitCriteria::CO2_SCRUBBER);
+        assert_eq!(filtered.reading, 0b01010);
+        assert_eq!(filtered.eliminations.len(), 3);
+    }
+
+    #[test]
+    fn should_apply_custom_criteria_given_other_tie_rules_and_lsb_first() {
+        let lines = vec![
+            "10".to_string(),
+            "01".to_string(),
+            "11".to_string(),
+            "00".to_string(),
+        ];
+        let mut report = parse_power_consumption_binary_by_line(&lines).unwrap();
+
+        let keep_both = BitCriteria {
+            tie: TieRule::KeepBoth,
+            ..BitCriteria::CO2_SCRUBBER
+        };
+        sort_readings_for_criteria(&mut report, &keep_both);
+        let filtered = get_final_reading_report(&report, &keep_both);
+        assert_eq!(filtered.eliminations[0].kept, None);
+        assert!(filtered.eliminations[0].eliminated.is_empty());
+        assert_eq!(filtered.reading, 0b00);
+
+        let previous_winner = BitCriteria {
+            tie: TieRule::PreviousWinner { otherwise: 0 },
+            ..BitCriteria::OXYGEN_GENERATOR
+        };
+        assert_eq!(get_final_reading(&report, &previous_winner), 0b00);
+
+        let lsb_first = BitCriteria {
+            order: BitOrder::LsbFirst,
+            ..BitCriteria::OXYGEN_GENERATOR
+        };
+        let lines = vec!["10".to_string(), "01".to_string(), "11".to_string()];
+        let mut report = parse_power_consumption_binary_by_line(&lines).unwrap();
+        sort_readings_for_criteria(&mut report, &lsb_first);
+        let filtered = get_final_reading_report(&report, &lsb_first);
+        assert_eq!(filtered.eliminations[0].column, 1);
+        assert_eq!(filtered.eliminations[0].eliminated, vec![0b10]);
+        assert_eq!(filtered.reading, 0b11);
+    }
+}
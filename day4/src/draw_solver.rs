@@ -0,0 +1,155 @@
+use crate::{play_bingo, BingoBoard, BingoRules};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WinOrder {
+    First,
+    Last,
+}
+
+// A draw order that makes `board_index` the first or the last board to win, or None when
+// there is none. The boards should not have been played on yet.
+//
+// To win first the board needs exactly one of its lines drawn, so trying every line and every
+// number of it as the last draw finds the shortest order there is. To win last every other
+// board has to win before it; the lines for those are picked greedily, fewest new numbers
+// first, so the order is short but not always the shortest. Every candidate is checked by
+// playing the game, so an order that is returned always works.
+pub fn get_draws_for_winner(
+    bingo_boards: &[BingoBoard],
+    board_index: usize,
+    order: WinOrder,
+    rules: &BingoRules,
+) -> Option<Vec<i64>> {
+    let target = bingo_boards.get(board_index)?;
+    let mut lines = target.get_lines(rules);
+    lines.sort_by_key(|(_, line)| line.len());
+
+    let mut shortest: Option<Vec<i64>> = None;
+    for (_, line) in &lines {
+        if shortest
+            .as_ref()
+            .is_some_and(|draws| draws.len() <= line.len())
+        {
+            break;
+        }
+        for (last_index, last) in line.iter().enumerate() {
+            let mut draws = line
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| *index != last_index)
+                .map(|(_, num)| *num)
+                .collect::<Vec<i64>>();
+            if order == WinOrder::Last {
+                match get_draws_for_other_boards(bingo_boards, board_index, &draws, *last, rules) {
+                    Some(other_draws) => draws.extend(other_draws),
+                    None => continue,
+                }
+            }
+            draws.push(*last);
+
+            let is_shorter = shortest
+                .as_ref()
+                .is_none_or(|shortest| draws.len() < shortest.len());
+            if is_shorter && is_winner(bingo_boards, board_index, order, &draws, rules) {
+                shortest = Some(draws);
+            }
+        }
+    }
+
+    shortest
+}
+
+fn is_winner(
+    bingo_boards: &[BingoBoard],
+    board_index: usize,
+    order: WinOrder,
+    draws: &[i64],
+    rules: &BingoRules,
+) -> bool {
+    let game = play_bingo(draws, bingo_boards, rules);
+    let winner = match order {
+        WinOrder::First => game.get_first_winner(),
+        WinOrder::Last => game.get_last_winner(),
+    };
+    winner.is_some_and(|win| win.board_index == board_index && win.draw_index + 1 == draws.len())
+}
+
+// Numbers that make every other board win while the target keeps missing `last`, drawn after
+// the `drawn` part of its own line.
+fn get_draws_for_other_boards(
+    bingo_boards: &[BingoBoard],
+    board_index: usize,
+    drawn: &[i64],
+    last: i64,
+    rules: &BingoRules,
+) -> Option<Vec<i64>> {
+    let mut target = bingo_boards[board_index].clone();
+    let mut seen = drawn.iter().copied().collect::<HashSet<i64>>();
+    for num in drawn {
+        target.mark(*num);
+    }
+    let mut others = bingo_boards
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != board_index)
+        .map(|(index, board)| (index, board.clone()))
+        .collect::<Vec<(usize, BingoBoard)>>();
+    for (_, board) in others.iter_mut() {
+        for num in drawn {
+            board.mark(*num);
+        }
+    }
+
+    let mut draws = vec![];
+    loop {
+        // The cheapest line of any board still to win that leaves the target without a win.
+        // Boards before the target may also win on the last draw, as they are checked first.
+        let mut cheapest: Option<Vec<i64>> = None;
+        for (index, board) in others.iter().filter(|(_, board)| !board.has_won(rules)) {
+            let may_share_last = *index < board_index;
+            let mut board_cheapest: Option<Vec<i64>> = None;
+            for (_, line) in board.get_lines(rules) {
+                if !may_share_last && line.contains(&last) {
+                    continue;
+                }
+                let missing = line
+                    .into_iter()
+                    .filter(|num| !seen.contains(num) && *num != last)
+                    .collect::<Vec<i64>>();
+                let mut probe = target.clone();
+                for num in &missing {
+                    probe.mark(*num);
+                }
+                let is_cheaper = board_cheapest
+                    .as_ref()
+                    .is_none_or(|c| missing.len() < c.len());
+                if !probe.has_won(rules) && is_cheaper {
+                    board_cheapest = Some(missing);
+                }
+            }
+            match board_cheapest {
+                None => return None,
+                // wins together with the target on the last draw
+                Some(missing) if missing.is_empty() => {}
+                Some(missing) => {
+                    if cheapest.as_ref().is_none_or(|c| missing.len() < c.len()) {
+                        cheapest = Some(missing);
+                    }
+                }
+            }
+        }
+        let missing = match cheapest {
+            Some(missing) => missing,
+            None => return Some(draws),
+        };
+        for num in missing {
+            seen.insert(num);
+            target.mark(num);
+            for (_, board) in others.iter_mut() {
+                board.mark(num);
+            }
+            draws.push(num);
+        }
+    }
+}
@@ -0,0 +1,517 @@
+use common::{
+    input_lines, lines_from_str, parse_lines_from_file, parse_lines_from_reader,
+    parse_lines_from_str, ParseError, Solution, SolveError,
+};
+use std::{collections::HashMap, io::BufRead, path::Path};
+
+mod draw_solver;
+
+pub use draw_solver::{get_draws_for_winner, WinOrder};
+
+// Any N×M board; `val_mapping` is 1 where a number has been marked, indexed [row][column].
+// `diagonal_sum` counts the marks on the main and the anti-diagonal, which only matter for
+// square boards.
+#[derive(Clone, Debug)]
+pub struct BingoBoard {
+    pub pos_mapping: HashMap<i64, (usize, usize)>,
+    pub val_mapping: Vec<Vec<i64>>,
+    pub row_sum: Vec<i64>,
+    pub column_sum: Vec<i64>,
+    pub diagonal_sum: Vec<i64>,
+}
+
+impl BingoBoard {
+    // The rows have to be the same length and the numbers unique, which the parser checks.
+    pub fn from_rows(rows: &[Vec<i64>]) -> BingoBoard {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut pos_mapping = HashMap::new();
+        for (row_index, row) in rows.iter().enumerate() {
+            for (column_index, num) in row.iter().enumerate() {
+                pos_mapping.insert(*num, (row_index, column_index));
+            }
+        }
+        BingoBoard {
+            pos_mapping,
+            val_mapping: vec![vec![0; width]; height],
+            row_sum: vec![0; height],
+            column_sum: vec![0; width],
+            diagonal_sum: vec![0; 2],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.column_sum.len()
+    }
+
+    pub fn height(&self) -> usize {
+        self.row_sum.len()
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.width() == self.height()
+    }
+
+    // Returns whether the number was on the board and not marked yet.
+    pub fn mark(&mut self, num: i64) -> bool {
+        let (x, y) = match self.pos_mapping.get(&num) {
+            Some((x, y)) => (*x, *y),
+            None => return false,
+        };
+        if self.val_mapping[x][y] == 1 {
+            return false;
+        }
+        self.val_mapping[x][y] = 1;
+        self.row_sum[x] += 1;
+        self.column_sum[y] += 1;
+        if self.is_square() {
+            if x == y {
+                self.diagonal_sum[0] += 1;
+            }
+            if x + y + 1 == self.width() {
+                self.diagonal_sum[1] += 1;
+            }
+        }
+        true
+    }
+
+    // Rows are checked before columns and columns before diagonals when several are full.
+    pub fn get_winning_line(&self, rules: &BingoRules) -> Option<BingoLine> {
+        let width = self.width() as i64;
+        let height = self.height() as i64;
+        if let Some(row) = self.row_sum.iter().position(|sum| *sum == width) {
+            return Some(BingoLine::Row(row));
+        }
+        if let Some(column) = self.column_sum.iter().position(|sum| *sum == height) {
+            return Some(BingoLine::Column(column));
+        }
+        if !rules.diagonals || !self.is_square() {
+            return None;
+        }
+        match self.diagonal_sum.iter().position(|sum| *sum == width) {
+            Some(0) => Some(BingoLine::Diagonal),
+            Some(_) => Some(BingoLine::AntiDiagonal),
+            None => None,
+        }
+    }
+
+    pub fn has_won(&self, rules: &BingoRules) -> bool {
+        self.get_winning_line(rules).is_some()
+    }
+
+    pub fn get_unmarked_sum(&self) -> i64 {
+        self.pos_mapping
+            .iter()
+            .filter(|(_, (x, y))| self.val_mapping[*x][*y] == 0)
+            .map(|(num, _)| num)
+            .sum()
+    }
+
+    // The numbers of every line that can win under the rules, in the order of `get_winning_line`.
+    pub fn get_lines(&self, rules: &BingoRules) -> Vec<(BingoLine, Vec<i64>)> {
+        let mut rows = vec![vec![0; self.width()]; self.height()];
+        for (num, (x, y)) in &self.pos_mapping {
+            rows[*x][*y] = *num;
+        }
+        let mut lines = vec![];
+        for (row_index, row) in rows.iter().enumerate() {
+            lines.push((BingoLine::Row(row_index), row.clone()));
+        }
+        for column_index in 0..self.width() {
+            let column = rows.iter().map(|row| row[column_index]).collect();
+            lines.push((BingoLine::Column(column_index), column));
+        }
+        if rules.diagonals && self.is_square() {
+            let size = self.width();
+            let diagonal = (0..size).map(|i| rows[i][i]).collect();
+            let anti_diagonal = (0..size).map(|i| rows[i][size - 1 - i]).collect();
+            lines.push((BingoLine::Diagonal, diagonal));
+            lines.push((BingoLine::AntiDiagonal, anti_diagonal));
+        }
+        lines
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BingoLine {
+    Row(usize),
+    Column(usize),
+    // from the top left to the bottom right corner
+    Diagonal,
+    // from the top right to the bottom left corner
+    AntiDiagonal,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BingoWin {
+    pub board_index: usize,
+    pub draw: i64,
+    pub draw_index: usize,
+    pub line: BingoLine,
+    pub unmarked_sum: i64,
+}
+
+impl BingoWin {
+    pub fn get_score(&self) -> i64 {
+        self.draw * self.unmarked_sum
+    }
+}
+
+// Every win in the order it happened; boards winning on the same draw are in board order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BingoGame {
+    pub wins: Vec<BingoWin>,
+    pub never_won: Vec<usize>,
+}
+
+impl BingoGame {
+    // the first winner is n = 0
+    pub fn get_nth_winner(&self, n: usize) -> Option<&BingoWin> {
+        self.wins.get(n)
+    }
+
+    pub fn get_first_winner(&self) -> Option<&BingoWin> {
+        self.wins.first()
+    }
+
+    // only once every board has won, like the puzzle asks
+    pub fn get_last_winner(&self) -> Option<&BingoWin> {
+        match self.never_won.is_empty() {
+            true => self.wins.last(),
+            false => None,
+        }
+    }
+
+    pub fn get_wins_on_draw(&self, draw_index: usize) -> impl Iterator<Item = &BingoWin> {
+        self.wins
+            .iter()
+            .filter(move |win| win.draw_index == draw_index)
+    }
+
+    pub fn get_win_of_board(&self, board_index: usize) -> Option<&BingoWin> {
+        self.wins.iter().find(|win| win.board_index == board_index)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BingoRules {
+    // a full diagonal of a square board wins as well
+    pub diagonals: bool,
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = (Vec<i64>, Vec<BingoBoard>);
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<(Vec<i64>, Vec<BingoBoard>), ParseError> {
+        let lines = lines_from_str(input);
+        Ok((parse_bingo_input(&lines)?, parse_bingo_boards(&lines)?))
+    }
+
+    fn part1(
+        (bingo_inputs, bingo_boards): &(Vec<i64>, Vec<BingoBoard>),
+    ) -> Result<i64, SolveError> {
+        let game = play_bingo(bingo_inputs, bingo_boards, &BingoRules::default());
+        Ok(game.get_first_winner().map_or(0, BingoWin::get_score))
+    }
+
+    fn part2(
+        (bingo_inputs, bingo_boards): &(Vec<i64>, Vec<BingoBoard>),
+    ) -> Result<i64, SolveError> {
+        let game = play_bingo(bingo_inputs, bingo_boards, &BingoRules::default());
+        Ok(game.get_last_winner().map_or(0, BingoWin::get_score))
+    }
+}
+
+pub fn read_bingo_input_from_file(filename: impl AsRef<Path>) -> Result<Vec<i64>, ParseError> {
+    parse_lines_from_file(filename, parse_bingo_input)
+}
+
+pub fn read_bingo_input_from_str(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse_lines_from_str(input, parse_bingo_input)
+}
+
+pub fn read_bingo_input_from_reader(reader: impl BufRead) -> Result<Vec<i64>, ParseError> {
+    parse_lines_from_reader(reader, parse_bingo_input)
+}
+
+pub fn parse_bingo_input(lines: &[String]) -> Result<Vec<i64>, ParseError> {
+    let mut nums = vec![];
+    let line = match input_lines(lines).next() {
+        Some(line) => line,
+        None => return Err(ParseError::for_whole_input("", "a line of bingo inputs")),
+    };
+    let splits = line.text.trim().split(',');
+    for split in splits {
+        let num = line.parse_number::<i64>(split, "a bingo number")?;
+        nums.push(num);
+    }
+    //println!("{:?}", nums);
+    Ok(nums)
+}
+
+pub fn get_bingo_boards_from_file(
+    filename: impl AsRef<Path>,
+) -> Result<Vec<BingoBoard>, ParseError> {
+    parse_lines_from_file(filename, parse_bingo_boards)
+}
+
+pub fn get_bingo_boards_from_str(input: &str) -> Result<Vec<BingoBoard>, ParseError> {
+    parse_lines_from_str(input, parse_bingo_boards)
+}
+
+pub fn get_bingo_boards_from_reader(reader: impl BufRead) -> Result<Vec<BingoBoard>, ParseError> {
+    parse_lines_from_reader(reader, parse_bingo_boards)
+}
+
+// Boards are blocks of rows after the line of bingo inputs, separated by any number of blank
+// lines. Every board can have its own size but its rows must be the same length.
+pub fn parse_bingo_boards(lines_from_file: &[String]) -> Result<Vec<BingoBoard>, ParseError> {
+    let mut bingo_boards = vec![];
+    let mut rows: Vec<Vec<i64>> = vec![];
+    let mut seen = HashMap::new();
+
+    for line in input_lines(lines_from_file).skip(1) {
+        //println!("{:?}", line);
+        let nums = line.text.split_whitespace().collect::<Vec<&str>>();
+        if nums.is_empty() {
+            if !rows.is_empty() {
+                bingo_boards.push(BingoBoard::from_rows(&rows));
+                rows.clear();
+                seen.clear();
+            }
+            continue;
+        }
+        if let Some(first_row) = rows.first() {
+            if nums.len() != first_row.len() {
+                return Err(line.error_at(
+                    line.text.trim(),
+                    format!("a board row of {} numbers", first_row.len()),
+                ));
+            }
+        }
+        let mut row = vec![];
+        for num_text in nums {
+            let num = line.parse_number::<i64>(num_text, "a bingo number")?;
+            if let Some(first_line) = seen.insert(num, line.number) {
+                return Err(line.error_at(
+                    num_text,
+                    format!("a number not already on the board in line {}", first_line),
+                ));
+            }
+            row.push(num);
+        }
+        rows.push(row);
+    }
+    if !rows.is_empty() {
+        bingo_boards.push(BingoBoard::from_rows(&rows));
+    }
+    //println!("bingo boards are {:?}", bingo_boards);
+    Ok(bingo_boards)
+}
+
+pub fn play_bingo(
+    bingo_inputs: &[i64],
+    bingo_boards: &[BingoBoard],
+    rules: &BingoRules,
+) -> BingoGame {
+    let mut bingo_boards = bingo_boards.to_vec();
+    let mut board_win_record = vec![false; bingo_boards.len()];
+    let mut game = BingoGame::default();
+
+    for (draw_index, bingo_input) in bingo_inputs.iter().enumerate() {
+        for (board_index, bingo_board) in bingo_boards.iter_mut().enumerate() {
+            if board_win_record[board_index] || !bingo_board.mark(*bingo_input) {
+                continue;
+            }
+            //println!("bingo board is {:?}", bingo_board);
+            if let Some(line) = bingo_board.get_winning_line(rules) {
+                board_win_record[board_index] = true;
+                game.wins.push(BingoWin {
+                    board_index,
+                    draw: *bingo_input,
+                    draw_index,
+                    line,
+                    unmarked_sum: bingo_board.get_unmarked_sum(),
+                });
+            }
+        }
+    }
+    game.never_won = (0..bingo_boards.len())
+        .filter(|board_index| !board_win_record[*board_index])
+        .collect();
+
+    game
+}
+
+pub fn get_sum_of_unmarked_number_in_board(bingo_board: &BingoBoard) -> i64 {
+    bingo_board.get_unmarked_sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_all_bingo_inputs_in_the_first_line_from_file_given_the_filename() {
+        let filename = "day4_test.txt";
+        let bingo_inputs = read_bingo_input_from_file(filename).unwrap();
+
+        assert_eq!(bingo_inputs.len(), 27);
+    }
+
+    #[test]
+    fn should_get_all_bingo_boards_from_file_given_the_filename() {
+        let filename = "day4_test.txt";
+        let boards = get_bingo_boards_from_file(filename).unwrap();
+
+        assert_eq!(boards.len(), 3);
+        assert_eq!(boards[0].pos_mapping.len(), 25);
+    }
+
+    #[test]
+    fn should_get_winner_given_the_bingo_inputs_and_bingo_boards() {
+        let filename = "day4_test.txt";
+        let bingo_inputs = read_bingo_input_from_file(filename).unwrap();
+        let bingo_boards = get_bingo_boards_from_file(filename).unwrap();
+        let game = play_bingo(&bingo_inputs, &bingo_boards, &BingoRules::default());
+
+        let winner = game.get_first_winner().unwrap();
+        assert_eq!(winner.board_index, 2);
+        assert_eq!((winner.draw, winner.draw_index), (24, 11));
+        assert_eq!(winner.line, BingoLine::Row(0));
+        assert_eq!(winner.unmarked_sum, 188);
+    }
+
+    #[test]
+    fn should_get_last_winner_given_the_bingo_inputs_and_bingo_boards() {
+        let filename = "day4_test.txt";
+        let bingo_inputs = read_bingo_input_from_file(filename).unwrap();
+        let bingo_boards = get_bingo_boards_from_file(filename).unwrap();
+        let game = play_bingo(&bingo_inputs, &bingo_boards, &BingoRules::default());
+
+        let winner = game.get_last_winner().unwrap();
+        assert_eq!(winner.board_index, 1);
+        assert_eq!(winner.draw, 13);
+        assert_eq!(winner.unmarked_sum, 148);
+        assert_eq!(game.get_nth_winner(1).unwrap().board_index, 0);
+        assert_eq!(
+            game.get_wins_on_draw(winner.draw_index).next(),
+            Some(winner)
+        );
+    }
+
+    #[test]
+    fn should_list_boards_that_never_win_given_too_few_draws() {
+        let filename = "day4_test.txt";
+        let bingo_inputs = read_bingo_input_from_file(filename).unwrap();
+        let bingo_boards = get_bingo_boards_from_file(filename).unwrap();
+        let game = play_bingo(&bingo_inputs[..12], &bingo_boards, &BingoRules::default());
+
+        assert_eq!(game.wins.len(), 1);
+        assert_eq!(game.never_won, vec![0, 1]);
+        assert_eq!(game.get_last_winner(), None);
+        assert_eq!(game.get_win_of_board(0), None);
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_test_input_through_the_solution_trait() {
+        let input = std::fs::read_to_string("day4_test.txt").unwrap();
+        let parsed = Day4::parse(&input).unwrap();
+
+        assert_eq!(Day4::part1(&parsed).unwrap(), 4512);
+        assert_eq!(Day4::part2(&parsed).unwrap(), 1924);
+    }
+
+    #[test]
+    fn should_get_boards_of_any_size_given_extra_blank_lines_and_trailing_whitespace() {
+        let input = "3,1,2,4\n\n\n 1  2 3 \n4 5 6\n\n\n7 8\n9 10 \n11 12\n\n";
+        let boards = get_bingo_boards_from_str(input).unwrap();
+
+        assert_eq!(boards.len(), 2);
+        assert_eq!((boards[0].width(), boards[0].height()), (3, 2));
+        assert_eq!((boards[1].width(), boards[1].height()), (2, 3));
+        assert_eq!(boards[1].pos_mapping[&12], (2, 1));
+
+        let bingo_inputs = read_bingo_input_from_str(input).unwrap();
+        let game = play_bingo(&bingo_inputs, &boards, &BingoRules::default());
+        let winner = game.get_first_winner().unwrap();
+        assert_eq!((winner.board_index, winner.draw), (0, 2));
+        assert_eq!(winner.line, BingoLine::Row(0));
+    }
+
+    #[test]
+    fn should_get_parse_error_given_a_duplicate_number_or_a_short_row() {
+        let error = get_bingo_boards_from_str("1,2\n\n1 2\n3 1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 3));
+        assert_eq!(
+            error.expected,
+            "a number not already on the board in line 3"
+        );
+
+        let error = get_bingo_boards_from_str("1,2\n\n1 2\n3\n").unwrap_err();
+        assert_eq!(error.expected, "a board row of 2 numbers");
+    }
+
+    #[test]
+    fn should_win_on_a_diagonal_only_given_the_diagonal_rule() {
+        let boards = get_bingo_boards_from_str("1,5,9\n\n1 2 3\n4 5 6\n7 8 9\n").unwrap();
+        let inputs = vec![1, 5, 9];
+
+        let game = play_bingo(&inputs, &boards, &BingoRules::default());
+        assert_eq!(game.get_first_winner(), None);
+        assert_eq!(game.never_won, vec![0]);
+
+        let game = play_bingo(&inputs, &boards, &BingoRules { diagonals: true });
+        let winner = game.get_first_winner().unwrap();
+        assert_eq!((winner.board_index, winner.draw), (0, 9));
+        assert_eq!(winner.line, BingoLine::Diagonal);
+        assert_eq!(winner.unmarked_sum, 30);
+    }
+
+    #[test]
+    fn should_get_shortest_draws_for_a_chosen_winner_given_the_test_boards() {
+        let filename = "day4_test.txt";
+        let bingo_boards = get_bingo_boards_from_file(filename).unwrap();
+        let rules = BingoRules::default();
+
+        for board_index in 0..bingo_boards.len() {
+            let draws =
+                get_draws_for_winner(&bingo_boards, board_index, WinOrder::First, &rules).unwrap();
+            assert_eq!(draws.len(), 5);
+            let game = play_bingo(&draws, &bingo_boards, &rules);
+            assert_eq!(game.get_first_winner().unwrap().board_index, board_index);
+
+            let draws =
+                get_draws_for_winner(&bingo_boards, board_index, WinOrder::Last, &rules).unwrap();
+            let game = play_bingo(&draws, &bingo_boards, &rules);
+            assert_eq!(game.get_last_winner().unwrap().board_index, board_index);
+        }
+    }
+
+    #[test]
+    fn should_get_no_draws_given_a_board_that_cannot_win_in_that_place() {
+        let boards = get_bingo_boards_from_str("1\n\n1 2\n3 4\n\n1 2\n3 4\n").unwrap();
+        let rules = BingoRules::default();
+
+        assert_eq!(
+            get_draws_for_winner(&boards, 1, WinOrder::First, &rules),
+            None
+        );
+        assert_eq!(
+            get_draws_for_winner(&boards, 0, WinOrder::Last, &rules),
+            None
+        );
+        assert_eq!(
+            get_draws_for_winner(&boards, 1, WinOrder::Last, &rules).map(|d| d.len()),
+            Some(2)
+        );
+        assert_eq!(
+            get_draws_for_winner(&boards, 2, WinOrder::First, &rules),
+            None
+        );
+    }
+}
@@ -0,0 +1,689 @@
+use common::{
+    input_lines, lines_from_str, parse_lines_from_file, parse_lines_from_reader,
+    parse_lines_from_str, Grid, InputLine, ParseError, Solution, SolveError,
+};
+use std::{collections::HashMap, error::Error, fmt, io::BufRead, path::Path};
+
+mod render;
+
+pub use render::{get_vent_map_ascii, get_vent_map_pgm, get_vent_map_ppm};
+
+// Up to this many cells the map is a dense grid, above it only the points on a line are kept.
+pub const MAX_DENSE_CELLS: u64 = 1 << 24;
+
+// A grid over bounds with more than MAX_DENSE_CELLS cells, which is neither built nor drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VentMapTooLarge {
+    // u128 because the width and height may both be above 32 bits
+    pub cells: u128,
+}
+
+impl VentMapTooLarge {
+    fn new(bounds: &VentBounds) -> VentMapTooLarge {
+        VentMapTooLarge {
+            cells: bounds.width() as u128 * bounds.height() as u128,
+        }
+    }
+}
+
+impl fmt::Display for VentMapTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "error: the vent map has {} cells, a grid holds at most {}",
+            self.cells, MAX_DENSE_CELLS
+        )
+    }
+}
+
+impl Error for VentMapTooLarge {}
+
+//TIP: 经验表明用tuple表示x，y不直观，还是要多用一个struct，把它们表示为x，y
+#[derive(Clone, Debug)]
+pub struct VentReading {
+    pub start: (i64, i64),
+    pub end: (i64, i64),
+}
+
+// Which vent lines count and which points of them are covered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineFilter {
+    // horizontal and vertical lines only, as in part 1
+    Straight,
+    // also lines at exactly 45°, as in part 2
+    StraightAndDiagonal,
+    // lines of any slope, covering only the points with integer coordinates exactly on them
+    LatticePoints,
+    // lines of any slope, covering the cells Bresenham's algorithm draws for them
+    Bresenham,
+}
+
+fn get_gcd(a: i64, b: i64) -> i64 {
+    match b {
+        0 => a,
+        _ => get_gcd(b, a % b),
+    }
+}
+
+impl VentReading {
+    // Empty for a line the filter leaves out. Straight and 45° lines have the same points in
+    // every mode that includes them.
+    pub fn get_points(&self, filter: LineFilter) -> Box<dyn Iterator<Item = (i64, i64)>> {
+        let (dx, dy) = (self.end.0 - self.start.0, self.end.1 - self.start.1);
+        let is_straight = dx == 0 || dy == 0;
+        let is_diagonal = dx.abs() == dy.abs();
+        let steps = match filter {
+            LineFilter::Straight if !is_straight => return Box::new(std::iter::empty()),
+            LineFilter::StraightAndDiagonal if !is_straight && !is_diagonal => {
+                return Box::new(std::iter::empty())
+            }
+            LineFilter::Bresenham => return Box::new(BresenhamPoints::new(self.start, self.end)),
+            _ => get_gcd(dx.abs(), dy.abs()),
+        };
+        let (x, y) = self.start;
+        if steps == 0 {
+            return Box::new(std::iter::once((x, y)));
+        }
+        let (step_x, step_y) = (dx / steps, dy / steps);
+        Box::new((0..=steps).map(move |i| (x + i * step_x, y + i * step_y)))
+    }
+}
+
+// The cells of a line of any slope, from start to end both included.
+#[derive(Clone, Debug)]
+pub struct BresenhamPoints {
+    point: (i64, i64),
+    end: (i64, i64),
+    step: (i64, i64),
+    delta: (i64, i64),
+    error: i64,
+    is_done: bool,
+}
+
+impl BresenhamPoints {
+    pub fn new(start: (i64, i64), end: (i64, i64)) -> BresenhamPoints {
+        let delta = ((end.0 - start.0).abs(), -(end.1 - start.1).abs());
+        BresenhamPoints {
+            point: start,
+            end,
+            step: ((end.0 - start.0).signum(), (end.1 - start.1).signum()),
+            delta,
+            error: delta.0 + delta.1,
+            is_done: false,
+        }
+    }
+}
+
+impl Iterator for BresenhamPoints {
+    type Item = (i64, i64);
+
+    fn next(&mut self) -> Option<(i64, i64)> {
+        if self.is_done {
+            return None;
+        }
+        let point = self.point;
+        if point == self.end {
+            self.is_done = true;
+            return Some(point);
+        }
+        let double_error = 2 * self.error;
+        if double_error >= self.delta.1 {
+            self.error += self.delta.1;
+            self.point.0 += self.step.0;
+        }
+        if double_error <= self.delta.0 {
+            self.error += self.delta.0;
+            self.point.1 += self.step.1;
+        }
+        Some(point)
+    }
+}
+
+// The smallest rectangle holding every vent, both corners included.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VentBounds {
+    pub x_min: i64,
+    pub y_min: i64,
+    pub x_max: i64,
+    pub y_max: i64,
+}
+
+impl VentBounds {
+    pub fn width(&self) -> u64 {
+        (self.x_max - self.x_min + 1) as u64
+    }
+
+    pub fn height(&self) -> u64 {
+        (self.y_max - self.y_min + 1) as u64
+    }
+
+    pub fn get_cell_count(&self) -> Option<u64> {
+        self.width().checked_mul(self.height())
+    }
+}
+
+// How many vent lines cover each point.
+pub trait OverlapMap {
+    fn add_point(&mut self, point: (i64, i64));
+    fn get_overlaps(&self, point: (i64, i64)) -> i64;
+    fn get_number_of_overlapping_points(&self) -> i64;
+    // the bounds of every vent the map is for, drawn or left out by the filter
+    fn get_bounds(&self) -> VentBounds;
+}
+
+// Dense map over the bounds of the vents, indexed from (x_min, y_min).
+#[derive(Clone, Debug)]
+pub struct VentMap {
+    pub val_mapping: Grid<i64>,
+    pub bounds: VentBounds,
+}
+
+impl VentMap {
+    pub fn new(bounds: VentBounds) -> VentMap {
+        VentMap {
+            val_mapping: Grid::new(bounds.width() as usize, bounds.height() as usize, 0),
+            bounds,
+        }
+    }
+
+    fn get_index(&self, (x, y): (i64, i64)) -> Option<(usize, usize)> {
+        let (x, y) = (x - self.bounds.x_min, y - self.bounds.y_min);
+        match self.val_mapping.contains(x, y) {
+            true => Some((x as usize, y as usize)),
+            false => None,
+        }
+    }
+}
+
+impl OverlapMap for VentMap {
+    fn add_point(&mut self, point: (i64, i64)) {
+        if let Some((x, y)) = self.get_index(point) {
+            self.val_mapping[(x, y)] += 1;
+        }
+    }
+
+    fn get_overlaps(&self, point: (i64, i64)) -> i64 {
+        self.get_index(point)
+            .and_then(|(x, y)| self.val_mapping.get(x, y))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn get_number_of_overlapping_points(&self) -> i64 {
+        self.val_mapping
+            .iter()
+            .filter(|overlaps| **overlaps >= 2)
+            .count() as i64
+    }
+
+    fn get_bounds(&self) -> VentBounds {
+        self.bounds
+    }
+}
+
+// Only the points some vent goes through, for coordinates too far apart for a grid.
+#[derive(Clone, Debug)]
+pub struct SparseVentMap {
+    pub val_mapping: HashMap<(i64, i64), i64>,
+    pub bounds: VentBounds,
+}
+
+impl SparseVentMap {
+    pub fn new(bounds: VentBounds) -> SparseVentMap {
+        SparseVentMap {
+            val_mapping: HashMap::new(),
+            bounds,
+        }
+    }
+}
+
+impl OverlapMap for SparseVentMap {
+    fn add_point(&mut self, point: (i64, i64)) {
+        *self.val_mapping.entry(point).or_insert(0) += 1;
+    }
+
+    fn get_overlaps(&self, point: (i64, i64)) -> i64 {
+        self.val_mapping.get(&point).copied().unwrap_or(0)
+    }
+
+    fn get_number_of_overlapping_points(&self) -> i64 {
+        self.val_mapping
+            .values()
+            .filter(|overlaps| **overlaps >= 2)
+            .count() as i64
+    }
+
+    fn get_bounds(&self) -> VentBounds {
+        self.bounds
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VentEngine {
+    Dense,
+    Sparse,
+    // dense up to MAX_DENSE_CELLS cells, sparse above
+    Auto,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<VentReading>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<VentReading>, ParseError> {
+        parse_vents_by_line(&lines_from_str(input))
+    }
+
+    fn part1(readings: &Vec<VentReading>) -> Result<i64, SolveError> {
+        Ok(get_number_of_overlapping_points_from_vents(
+            readings,
+            LineFilter::Straight,
+        ))
+    }
+
+    fn part2(readings: &Vec<VentReading>) -> Result<i64, SolveError> {
+        Ok(get_number_of_overlapping_points_from_vents(
+            readings,
+            LineFilter::StraightAndDiagonal,
+        ))
+    }
+}
+
+pub fn read_vents_from_file_by_line(
+    filename: impl AsRef<Path>,
+) -> Result<Vec<VentReading>, ParseError> {
+    parse_lines_from_file(filename, parse_vents_by_line)
+}
+
+pub fn read_vents_from_str_by_line(input: &str) -> Result<Vec<VentReading>, ParseError> {
+    parse_lines_from_str(input, parse_vents_by_line)
+}
+
+pub fn read_vents_from_reader_by_line(
+    reader: impl BufRead,
+) -> Result<Vec<VentReading>, ParseError> {
+    parse_lines_from_reader(reader, parse_vents_by_line)
+}
+
+// Coordinates are 32-bit so that differences between them never overflow.
+fn parse_vent_point(line: &InputLine, point: Option<&str>) -> Result<(i64, i64), ParseError> {
+    let point = line.expect_token(point, "a point like x,y")?;
+    let mut split = point.split(',');
+    let x = line.expect_token(split.next(), "an x coordinate")?;
+    let y = line.expect_token(split.next(), "a y coordinate")?;
+    if let Some(extra) = split.next() {
+        return Err(line.error_at(extra, "a point like x,y"));
+    }
+    let x = line.parse_number::<i32>(x, "a 32-bit x coordinate")?;
+    let y = line.parse_number::<i32>(y, "a 32-bit y coordinate")?;
+    Ok((x as i64, y as i64))
+}
+
+pub fn parse_vents_by_line(lines_from_file: &[String]) -> Result<Vec<VentReading>, ParseError> {
+    let mut readings = vec![];
+    for line in input_lines(lines_from_file) {
+        // println!("{:?}", line);
+        let mut split = line.text.trim().split("->");
+        let start = parse_vent_point(&line, split.next())?;
+        let end = parse_vent_point(&line, split.next())?;
+        if let Some(extra) = split.next() {
+            return Err(line.error_at(extra, "end of line"));
+        }
+        readings.push(VentReading { start, end });
+    }
+    Ok(readings)
+}
+
+pub fn get_vent_bounds(vent_readings: &[VentReading]) -> VentBounds {
+    let mut points = vent_readings
+        .iter()
+        .flat_map(|reading| [reading.start, reading.end]);
+    let (x, y) = match points.next() {
+        Some(point) => point,
+        None => return VentBounds::default(),
+    };
+    points.fold(
+        VentBounds {
+            x_min: x,
+            y_min: y,
+            x_max: x,
+            y_max: y,
+        },
+        |bounds, (x, y)| VentBounds {
+            x_min: bounds.x_min.min(x),
+            y_min: bounds.y_min.min(y),
+            x_max: bounds.x_max.max(x),
+            y_max: bounds.y_max.max(y),
+        },
+    )
+}
+
+pub fn fill_overlap_map(
+    vent_readings: &[VentReading],
+    filter: LineFilter,
+    overlap_map: &mut impl OverlapMap,
+) {
+    for vent_reading in vent_readings {
+        for point in vent_reading.get_points(filter) {
+            overlap_map.add_point(point);
+        }
+    }
+    //println!("the vent map is {:?}", overlap_map);
+}
+
+pub fn get_number_of_overlapping_points_from_vents(
+    vent_readings: &[VentReading],
+    filter: LineFilter,
+) -> i64 {
+    let bounds = get_vent_bounds(vent_readings);
+    fill_vent_map(vent_readings, filter, bounds, fits_dense_grid(&bounds))
+        .get_number_of_overlapping_points()
+}
+
+pub fn get_number_of_overlapping_points_with_engine(
+    vent_readings: &[VentReading],
+    filter: LineFilter,
+    engine: VentEngine,
+) -> Result<i64, VentMapTooLarge> {
+    let vent_map = get_filled_vent_map(vent_readings, filter, engine)?;
+    Ok(vent_map.get_number_of_overlapping_points())
+}
+
+// The map after every vent is drawn on it, for rendering or looking up single points. The dense
+// engine refuses bounds too large for a grid rather than trying to allocate one.
+pub fn get_filled_vent_map(
+    vent_readings: &[VentReading],
+    filter: LineFilter,
+    engine: VentEngine,
+) -> Result<Box<dyn OverlapMap>, VentMapTooLarge> {
+    let bounds = get_vent_bounds(vent_readings);
+    let is_dense = match engine {
+        VentEngine::Dense if !fits_dense_grid(&bounds) => {
+            return Err(VentMapTooLarge::new(&bounds))
+        }
+        VentEngine::Dense => true,
+        VentEngine::Sparse => false,
+        VentEngine::Auto => fits_dense_grid(&bounds),
+    };
+    Ok(fill_vent_map(vent_readings, filter, bounds, is_dense))
+}
+
+fn fits_dense_grid(bounds: &VentBounds) -> bool {
+    bounds
+        .get_cell_count()
+        .is_some_and(|cells| cells <= MAX_DENSE_CELLS)
+}
+
+fn fill_vent_map(
+    vent_readings: &[VentReading],
+    filter: LineFilter,
+    bounds: VentBounds,
+    is_dense: bool,
+) -> Box<dyn OverlapMap> {
+    if is_dense {
+        let mut vent_map = VentMap::new(bounds);
+        fill_overlap_map(vent_readings, filter, &mut vent_map);
+        Box::new(vent_map)
+    } else {
+        let mut vent_map = SparseVentMap::new(bounds);
+        fill_overlap_map(vent_readings, filter, &mut vent_map);
+        Box::new(vent_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_all_actions_from_file_by_line_given_the_filename() {
+        let filename = "day5_test.txt";
+        let readings = read_vents_from_file_by_line(filename).unwrap();
+
+        assert_eq!(readings.len(), 10);
+        let bounds = get_vent_bounds(&readings);
+        assert_eq!((bounds.x_min, bounds.y_min), (0, 0));
+        assert_eq!((bounds.x_max, bounds.y_max), (9, 9));
+    }
+
+    #[test]
+    fn should_get_parse_error_with_position_given_a_bad_vent_line() {
+        let lines = vec!["0,9 -> 5,9".to_string(), "8,0 -> 0,x".to_string()];
+        let error = parse_vents_by_line(&lines).err().unwrap();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 10);
+        assert_eq!(error.text, "x");
+
+        let lines = vec!["0,9 5,9".to_string()];
+        let error = parse_vents_by_line(&lines).err().unwrap();
+
+        assert_eq!(error.column, 7);
+        assert_eq!(error.expected, "a point like x,y");
+    }
+
+    #[test]
+    fn should_get_right_number_of_overlapping_points_without_diagonal_cases_given_the_vents_input_and_vent_map(
+    ) {
+        let filename = "day5_test.txt";
+        let readings = read_vents_from_file_by_line(filename).unwrap();
+
+        let overlapping_points =
+            get_number_of_overlapping_points_from_vents(&readings, LineFilter::Straight);
+
+        assert_eq!(overlapping_points, 5);
+    }
+
+    #[test]
+    fn should_get_right_number_of_overlapping_points_with_diagonal_cases_given_the_vents_input_and_vent_map(
+    ) {
+        let filename = "day5_test.txt";
+        let readings = read_vents_from_file_by_line(filename).unwrap();
+
+        let overlapping_points =
+            get_number_of_overlapping_points_from_vents(&readings, LineFilter::StraightAndDiagonal);
+
+        assert_eq!(overlapping_points, 12);
+    }
+
+    #[test]
+    fn should_count_overlapping_points_given_a_map_wider_than_it_is_tall() {
+        let lines = vec!["0,0 -> 9,0".to_string(), "9,0 -> 9,1".to_string()];
+        let readings = parse_vents_by_line(&lines).unwrap();
+        let vent_map = VentMap::new(get_vent_bounds(&readings));
+
+        assert_eq!(vent_map.val_mapping.width(), 10);
+        assert_eq!(vent_map.val_mapping.height(), 2);
+        assert_eq!(
+            get_number_of_overlapping_points_from_vents(&readings, LineFilter::Straight),
+            1
+        );
+    }
+
+    #[test]
+    fn should_get_same_count_from_dense_and_sparse_engines_given_the_test_vents() {
+        let filename = "day5_test.txt";
+        let readings = read_vents_from_file_by_line(filename).unwrap();
+
+        for filter in [
+            LineFilter::Straight,
+            LineFilter::StraightAndDiagonal,
+            LineFilter::LatticePoints,
+            LineFilter::Bresenham,
+        ] {
+            assert_eq!(
+                get_number_of_overlapping_points_with_engine(&readings, filter, VentEngine::Dense)
+                    .unwrap(),
+                get_number_of_overlapping_points_with_engine(&readings, filter, VentEngine::Sparse)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn should_refuse_a_dense_map_given_coordinates_too_far_apart_for_a_grid() {
+        let lines = vec![
+            "-2147483648,0 -> -2147483647,0".to_string(),
+            "2147483647,0 -> 2147483647,5".to_string(),
+        ];
+        let readings = parse_vents_by_line(&lines).unwrap();
+
+        let error = get_filled_vent_map(&readings, LineFilter::Straight, VentEngine::Dense)
+            .err()
+            .unwrap();
+        assert_eq!(error.cells, 6 << 32);
+        assert!(error.to_string().contains("25769803776 cells"));
+        assert_eq!(
+            get_number_of_overlapping_points_with_engine(
+                &readings,
+                LineFilter::Straight,
+                VentEngine::Auto
+            ),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn should_count_overlapping_points_given_coordinates_far_apart() {
+        let lines = vec![
+            "-100000,5 -> 100000,5".to_string(),
+            "0,-100000 -> 0,100000".to_string(),
+            "99999,4 -> 100001,6".to_string(),
+        ];
+        let readings = parse_vents_by_line(&lines).unwrap();
+
+        assert_eq!(
+            get_number_of_overlapping_points_from_vents(&readings, LineFilter::Straight),
+            1
+        );
+        assert_eq!(
+            get_number_of_overlapping_points_from_vents(&readings, LineFilter::StraightAndDiagonal),
+            2
+        );
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_test_input_through_the_solution_trait() {
+        let input = std::fs::read_to_string("day5_test.txt").unwrap();
+        let parsed = Day5::parse(&input).unwrap();
+
+        assert_eq!(Day5::part1(&parsed).unwrap(), 5);
+        assert_eq!(Day5::part2(&parsed).unwrap(), 12);
+    }
+
+    #[test]
+    fn should_get_points_of_a_steep_line_given_each_line_filter() {
+        let reading = VentReading {
+            start: (0, 0),
+            end: (4, 2),
+        };
+        let points = |filter| reading.get_points(filter).collect::<Vec<(i64, i64)>>();
+
+        assert!(points(LineFilter::Straight).is_empty());
+        assert!(points(LineFilter::StraightAndDiagonal).is_empty());
+        assert_eq!(
+            points(LineFilter::LatticePoints),
+            vec![(0, 0), (2, 1), (4, 2)]
+        );
+        assert_eq!(
+            points(LineFilter::Bresenham),
+            vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]
+        );
+
+        let reading = VentReading {
+            start: (3, 7),
+            end: (0, 4),
+        };
+        assert_eq!(
+            reading
+                .get_points(LineFilter::Bresenham)
+                .collect::<Vec<_>>(),
+            reading
+                .get_points(LineFilter::StraightAndDiagonal)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_count_lines_of_any_slope_given_the_lattice_and_bresenham_filters() {
+        let lines = vec!["0,0 -> 4,2".to_string(), "3,0 -> 3,2".to_string()];
+        let readings = parse_vents_by_line(&lines).unwrap();
+
+        assert_eq!(
+            get_number_of_overlapping_points_from_vents(&readings, LineFilter::LatticePoints),
+            0
+        );
+        assert_eq!(
+            get_number_of_overlapping_points_from_vents(&readings, LineFilter::Bresenham),
+            1
+        );
+    }
+
+    #[test]
+    fn should_render_the_puzzle_drawing_given_the_test_vents() {
+        let filename = "day5_test.txt";
+        let readings = read_vents_from_file_by_line(filename).unwrap();
+        for engine in [VentEngine::Dense, VentEngine::Sparse] {
+            let vent_map =
+                get_filled_vent_map(&readings, LineFilter::StraightAndDiagonal, engine).unwrap();
+            let ascii = get_vent_map_ascii(vent_map.as_ref()).unwrap();
+            assert_eq!(ascii.lines().next(), Some("1.1....11."));
+            assert_eq!(ascii.lines().nth(4), Some(".112313211"));
+            assert_eq!(vent_map.get_overlaps((4, 4)), 3);
+        }
+    }
+
+    #[test]
+    fn should_render_the_same_drawing_from_both_engines_given_vents_left_out_by_the_filter() {
+        let lines = vec!["0,0 -> 2,0".to_string(), "5,5 -> 7,7".to_string()];
+        let readings = parse_vents_by_line(&lines).unwrap();
+
+        let renders = [VentEngine::Dense, VentEngine::Sparse].map(|engine| {
+            let vent_map = get_filled_vent_map(&readings, LineFilter::Straight, engine).unwrap();
+            assert_eq!(vent_map.get_bounds(), get_vent_bounds(&readings));
+            get_vent_map_ascii(vent_map.as_ref()).unwrap()
+        });
+        assert_eq!(renders[0], renders[1]);
+        assert_eq!(renders[0].lines().count(), 8);
+        assert_eq!(renders[0].lines().next(), Some("111....."));
+    }
+
+    #[test]
+    fn should_render_binary_heatmaps_given_the_test_vents() {
+        let filename = "day5_test.txt";
+        let readings = read_vents_from_file_by_line(filename).unwrap();
+        let vent_map =
+            get_filled_vent_map(&readings, LineFilter::Straight, VentEngine::Auto).unwrap();
+
+        let header = b"P5\n10 10\n255\n";
+        let pgm = get_vent_map_pgm(vent_map.as_ref()).unwrap();
+        assert_eq!(&pgm[..header.len()], header);
+        assert_eq!(pgm.len(), header.len() + 100);
+        assert_eq!(pgm[header.len()..].iter().max(), Some(&255));
+
+        let ppm = get_vent_map_ppm(vent_map.as_ref()).unwrap();
+        assert!(ppm.starts_with(b"P6\n10 10\n255\n"));
+        assert_eq!(ppm.len(), header.len() + 300);
+    }
+
+    #[test]
+    fn should_refuse_to_render_given_a_sparse_map_with_huge_bounds() {
+        let lines = vec![
+            "-2147483648,-2147483648 -> -2147483647,-2147483648".to_string(),
+            "2147483647,2147483647 -> 2147483647,2147483646".to_string(),
+        ];
+        let readings = parse_vents_by_line(&lines).unwrap();
+        let vent_map =
+            get_filled_vent_map(&readings, LineFilter::Straight, VentEngine::Auto).unwrap();
+
+        let bounds = vent_map.get_bounds();
+        assert_eq!((bounds.x_min, bounds.x_max), (-2147483648, 2147483647));
+        let error = get_vent_map_ascii(vent_map.as_ref()).unwrap_err();
+        assert_eq!(error.cells, 1 << 64);
+        assert!(get_vent_map_pgm(vent_map.as_ref()).is_err());
+        assert!(get_vent_map_ppm(vent_map.as_ref()).is_err());
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{OverlapMap, VentBounds, VentMapTooLarge, MAX_DENSE_CELLS};
+
+// Rendering walks every cell of the map's bounds, so it stops where a dense grid does.
+fn get_renderable_bounds(overlap_map: &dyn OverlapMap) -> Result<VentBounds, VentMapTooLarge> {
+    let bounds = overlap_map.get_bounds();
+    match bounds.get_cell_count() {
+        Some(cells) if cells <= MAX_DENSE_CELLS => Ok(bounds),
+        _ => Err(VentMapTooLarge::new(&bounds)),
+    }
+}
+
+fn get_max_overlaps(overlap_map: &dyn OverlapMap, bounds: &VentBounds) -> i64 {
+    let mut max = 0;
+    for y in bounds.y_min..=bounds.y_max {
+        for x in bounds.x_min..=bounds.x_max {
+            max = max.max(overlap_map.get_overlaps((x, y)));
+        }
+    }
+    max
+}
+
+// The puzzle's drawing: `.` where no vent is, the number of vents elsewhere and `#` from 10
+// on, one line per y.
+pub fn get_vent_map_ascii(overlap_map: &dyn OverlapMap) -> Result<String, VentMapTooLarge> {
+    let bounds = get_renderable_bounds(overlap_map)?;
+    let mut ascii = String::new();
+    for y in bounds.y_min..=bounds.y_max {
+        for x in bounds.x_min..=bounds.x_max {
+            ascii.push(match overlap_map.get_overlaps((x, y)) {
+                0 => '.',
+                overlaps @ 1..=9 => char::from(b'0' + overlaps as u8),
+                _ => '#',
+            });
+        }
+        ascii.push('\n');
+    }
+    Ok(ascii)
+}
+
+// Brightness of every cell scaled so the most overlapped one is 255.
+fn get_levels(overlap_map: &dyn OverlapMap, bounds: &VentBounds) -> Vec<u8> {
+    let max = get_max_overlaps(overlap_map, bounds).max(1);
+    let mut levels = Vec::with_capacity((bounds.width() * bounds.height()) as usize);
+    for y in bounds.y_min..=bounds.y_max {
+        for x in bounds.x_min..=bounds.x_max {
+            levels.push((overlap_map.get_overlaps((x, y)) * 255 / max) as u8);
+        }
+    }
+    levels
+}
+
+// Binary greyscale (P5) heatmap, black where no vent is.
+pub fn get_vent_map_pgm(overlap_map: &dyn OverlapMap) -> Result<Vec<u8>, VentMapTooLarge> {
+    let bounds = get_renderable_bounds(overlap_map)?;
+    let mut pgm = format!("P5\n{} {}\n255\n", bounds.width(), bounds.height()).into_bytes();
+    pgm.extend(get_levels(overlap_map, &bounds));
+    Ok(pgm)
+}
+
+// Binary colour (P6) heatmap going from black through red and yellow to white.
+pub fn get_vent_map_ppm(overlap_map: &dyn OverlapMap) -> Result<Vec<u8>, VentMapTooLarge> {
+    let bounds = get_renderable_bounds(overlap_map)?;
+    let mut ppm = format!("P6\n{} {}\n255\n", bounds.width(), bounds.height()).into_bytes();
+    for level in get_levels(overlap_map, &bounds) {
+        let level = level as u16 * 3;
+        ppm.extend([
+            level.min(255) as u8,
+            level.saturating_sub(255).min(255) as u8,
+            level.saturating_sub(510) as u8,
+        ]);
+    }
+    Ok(ppm)
+}
@@ -0,0 +1,155 @@
+use crate::{get_initial_state, get_state_count, get_transitions, PopulationModel};
+use common::BigUint;
+use std::num::NonZeroU64;
+
+// What the transition matrix is computed over: exact big integers or integers modulo a number.
+pub trait MatrixValue: Clone + PartialEq {
+    fn zero(&self) -> Self;
+    fn one(&self) -> Self;
+    fn add(&self, other: &Self) -> Self;
+    fn mul(&self, other: &Self) -> Self;
+}
+
+impl MatrixValue for BigUint {
+    fn zero(&self) -> Self {
+        BigUint::zero()
+    }
+
+    fn one(&self) -> Self {
+        BigUint::from(1)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        BigUint::add(self, other)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        BigUint::mul(self, other)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Modular {
+    pub value: u64,
+    pub modulus: NonZeroU64,
+}
+
+impl MatrixValue for Modular {
+    fn zero(&self) -> Self {
+        Modular { value: 0, ..*self }
+    }
+
+    fn one(&self) -> Self {
+        Modular {
+            value: 1 % self.modulus.get(),
+            ..*self
+        }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let value = (self.value as u128 + other.value as u128) % self.modulus.get() as u128;
+        Modular {
+            value: value as u64,
+            ..*self
+        }
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        let value = self.value as u128 * other.value as u128 % self.modulus.get() as u128;
+        Modular {
+            value: value as u64,
+            ..*self
+        }
+    }
+}
+
+// Square matrix, [from][to] like the transitions.
+type Matrix<T> = Vec<Vec<T>>;
+
+fn multiply<T: MatrixValue>(a: &Matrix<T>, b: &Matrix<T>, zero: &T) -> Matrix<T> {
+    let size = a.len();
+    let mut product = vec![vec![zero.clone(); size]; size];
+    for i in 0..size {
+        for k in 0..size {
+            if a[i][k] == *zero {
+                continue;
+            }
+            for j in 0..size {
+                product[i][j] = product[i][j].add(&a[i][k].mul(&b[k][j]));
+            }
+        }
+    }
+    product
+}
+
+// The state counts after `days`, by squaring the transition matrix: O(states³ log days).
+// For the puzzle's model the matrix is 9×9.
+fn get_state_after_days<T: MatrixValue>(
+    fishes: &[usize],
+    days: u64,
+    model: &PopulationModel,
+    unit: &T,
+    from_count: impl Fn(u128) -> T,
+) -> Vec<T> {
+    let (timers, ages) = get_state_count(fishes, model);
+    let size = timers * ages;
+    let zero = unit.zero();
+
+    let mut power = vec![vec![zero.clone(); size]; size];
+    for (from, to) in get_transitions(timers, ages, model) {
+        power[from][to] = power[from][to].add(&unit.one());
+    }
+    let mut result = vec![vec![zero.clone(); size]; size];
+    for (i, row) in result.iter_mut().enumerate() {
+        row[i] = unit.one();
+    }
+    let mut days = days;
+    while days > 0 {
+        if days & 1 == 1 {
+            result = multiply(&result, &power, &zero);
+        }
+        days >>= 1;
+        if days > 0 {
+            power = multiply(&power, &power, &zero);
+        }
+    }
+
+    let initial = get_initial_state(fishes, model);
+    (0..size)
+        .map(|to| {
+            initial
+                .iter()
+                .enumerate()
+                .fold(zero.clone(), |sum, (from, count)| {
+                    sum.add(&from_count(*count).mul(&result[from][to]))
+                })
+        })
+        .collect()
+}
+
+// Exact, however big; the numbers grow by about one bit every seven days.
+pub fn get_population_exact(fishes: &[usize], days: u64, model: &PopulationModel) -> BigUint {
+    let unit = BigUint::zero();
+    get_state_after_days(fishes, days, model, &unit, BigUint::from)
+        .iter()
+        .fold(BigUint::zero(), |total, count| total.add(count))
+}
+
+// The population modulo `modulus`, None for a modulus of 0; fine for days like 10^12.
+pub fn get_population_modulo(
+    fishes: &[usize],
+    days: u64,
+    model: &PopulationModel,
+    modulus: u64,
+) -> Option<u64> {
+    let modulus = NonZeroU64::new(modulus)?;
+    let unit = Modular { value: 0, modulus };
+    let from_count = |count: u128| Modular {
+        value: (count % modulus.get() as u128) as u64,
+        modulus,
+    };
+    let total = get_state_after_days(fishes, days, model, &unit, from_count)
+        .iter()
+        .fold(unit.zero(), |total, count| total.add(count));
+    Some(total.value)
+}
@@ -0,0 +1,213 @@
+use common::{
+    input_lines, lines_from_str, parse_lines_from_file, parse_lines_from_reader,
+    parse_lines_from_str, ParseError, Solution, SolveError,
+};
+use std::{io::BufRead, path::Path};
+
+mod fast_forward;
+mod population;
+
+pub use fast_forward::{get_population_exact, get_population_modulo, MatrixValue, Modular};
+pub use population::{
+    get_initial_state, get_population_after_days, get_population_series, get_state_count,
+    get_transitions, PopulationModel, PopulationOverflow,
+};
+
+// Each timer is a state of the population, so a bigger timer means a bigger transition matrix.
+pub const MAX_FISH_TIMER: usize = 255;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<usize>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        parse_fishes_from_first_line(&lines_from_str(input))
+    }
+
+    fn part1(fishes: &Vec<usize>) -> Result<u128, SolveError> {
+        Ok(get_fish_count_after_spawning(fishes, 80)?)
+    }
+
+    fn part2(fishes: &Vec<usize>) -> Result<u128, SolveError> {
+        Ok(get_fish_count_after_spawning(fishes, 256)?)
+    }
+}
+
+pub fn read_fishes_from_file_by_line(filename: impl AsRef<Path>) -> Result<Vec<usize>, ParseError> {
+    parse_lines_from_file(filename, parse_fishes_from_first_line)
+}
+
+pub fn read_fishes_from_str_by_line(input: &str) -> Result<Vec<usize>, ParseError> {
+    parse_lines_from_str(input, parse_fishes_from_first_line)
+}
+
+pub fn read_fishes_from_reader_by_line(reader: impl BufRead) -> Result<Vec<usize>, ParseError> {
+    parse_lines_from_reader(reader, parse_fishes_from_first_line)
+}
+
+// Timers up to MAX_FISH_TIMER are read so that other population models can be fed; for the
+// puzzle the timers are 0 to 8.
+pub fn parse_fishes_from_first_line(lines: &[String]) -> Result<Vec<usize>, ParseError> {
+    let line = match input_lines(lines).next() {
+        Some(line) => line,
+        None => return Err(ParseError::for_whole_input("", "a line of fish timers")),
+    };
+    let mut fishes = vec![];
+    for fish in line.text.trim().split(',') {
+        let expected = format!("a fish timer from 0 to {}", MAX_FISH_TIMER);
+        let timer = line.parse_number::<usize>(fish, &expected)?;
+        if timer > MAX_FISH_TIMER {
+            return Err(line.error_at(fish.trim(), expected));
+        }
+        fishes.push(timer);
+    }
+    Ok(fishes)
+}
+
+pub fn get_fish_count_after_spawning(
+    fishes: &[usize],
+    spawn_days: usize,
+) -> Result<u128, PopulationOverflow> {
+    get_population_after_days(fishes, spawn_days, &PopulationModel::LANTERNFISH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_all_initial_fishes_from_file_by_first_line_given_the_filename() {
+        let filename = "day6_test.txt";
+        let fishes = read_fishes_from_file_by_line(filename).unwrap();
+
+        assert_eq!(fishes.len(), 5);
+    }
+
+    #[test]
+    fn should_get_right_number_of_fishes_from_given_days_of_spawning() {
+        let filename = "day6_test.txt";
+        let fishes = read_fishes_from_file_by_line(filename).unwrap();
+
+        let fish_count = get_fish_count_after_spawning(&fishes, 18).unwrap();
+
+        assert_eq!(fish_count, 26);
+
+        let fish_count = get_fish_count_after_spawning(&fishes, 80).unwrap();
+
+        assert_eq!(fish_count, 5934);
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_test_input_through_the_solution_trait() {
+        let input = std::fs::read_to_string("day6_test.txt").unwrap();
+        let parsed = Day6::parse(&input).unwrap();
+
+        assert_eq!(Day6::part1(&parsed).unwrap(), 5934);
+        assert_eq!(Day6::part2(&parsed).unwrap(), 26984457539);
+    }
+
+    #[test]
+    fn should_get_population_series_given_other_timers_and_a_lifespan() {
+        let fishes = vec![0];
+        let series = get_population_series(&fishes, 18, &PopulationModel::LANTERNFISH).unwrap();
+        assert_eq!(series[..9], [1, 2, 2, 2, 2, 2, 2, 2, 3]);
+        assert_eq!(
+            series[18],
+            get_fish_count_after_spawning(&fishes, 18).unwrap()
+        );
+
+        let model = PopulationModel {
+            reset_timer: 1,
+            newborn_timer: 1,
+            max_lifespan: Some(3),
+        };
+        // a fish spawns every second day and is gone on its fourth day, before it spawns again
+        let series = get_population_series(&fishes, 5, &model).unwrap();
+        assert_eq!(series, vec![1, 2, 2, 2, 1, 2]);
+    }
+
+    #[test]
+    fn should_get_parse_error_given_a_timer_above_the_maximum() {
+        assert_eq!(
+            read_fishes_from_str_by_line("3,255\n").unwrap(),
+            vec![3, 255]
+        );
+
+        let error = read_fishes_from_str_by_line("3,256,1\n").unwrap_err();
+        assert!(error.to_string().contains("a fish timer from 0 to 255"));
+        assert!(error.to_string().contains("`256`"));
+    }
+
+    #[test]
+    fn should_get_overflow_error_given_too_many_days() {
+        let fishes = vec![3, 4, 3, 1, 2];
+        let error = get_fish_count_after_spawning(&fishes, 2000).unwrap_err();
+
+        assert!(error.day > 256 && error.day < 2000);
+        assert!(error.to_string().contains(&format!("day {}", error.day)));
+    }
+
+    #[test]
+    fn should_get_same_population_by_matrix_power_as_by_the_loop_given_small_days() {
+        let filename = "day6_test.txt";
+        let fishes = read_fishes_from_file_by_line(filename).unwrap();
+        let model = PopulationModel {
+            max_lifespan: Some(20),
+            ..PopulationModel::LANTERNFISH
+        };
+
+        for days in 0..=300 {
+            let count = get_fish_count_after_spawning(&fishes, days).unwrap();
+            let exact = get_population_exact(&fishes, days as u64, &PopulationModel::LANTERNFISH);
+            assert_eq!(exact.to_u128(), Some(count));
+            assert_eq!(
+                get_population_modulo(
+                    &fishes,
+                    days as u64,
+                    &PopulationModel::LANTERNFISH,
+                    1_000_000_007
+                ),
+                Some((count % 1_000_000_007) as u64)
+            );
+        }
+        for days in [0, 1, 7, 30, 64] {
+            assert_eq!(
+                get_population_exact(&fishes, days, &model).to_u128(),
+                Some(get_population_after_days(&fishes, days as usize, &model).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn should_get_population_beyond_128_bits_given_the_exact_mode() {
+        let fishes = vec![3, 4, 3, 1, 2];
+        let model = PopulationModel::LANTERNFISH;
+
+        let exact = get_population_exact(&fishes, 2000, &model);
+        assert_eq!(exact.to_u128(), None);
+        let digits = exact.to_string();
+        let modulus = 998_244_353;
+        let remainder = digits
+            .bytes()
+            .fold(0, |r, d| (r * 10 + (d - b'0') as u64) % modulus);
+        assert_eq!(
+            get_population_modulo(&fishes, 2000, &model, modulus),
+            Some(remainder)
+        );
+        assert!(
+            get_population_modulo(&fishes, 1_000_000_000_000, &model, modulus).unwrap() < modulus
+        );
+    }
+
+    #[test]
+    fn should_get_no_population_given_a_modulus_of_0() {
+        let fishes = vec![3, 4, 3, 1, 2];
+        let model = PopulationModel::LANTERNFISH;
+
+        assert_eq!(get_population_modulo(&fishes, 80, &model, 0), None);
+        assert_eq!(get_population_modulo(&fishes, 80, &model, 1), Some(0));
+    }
+}
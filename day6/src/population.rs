@@ -0,0 +1,149 @@
+use std::{error::Error, fmt};
+
+// How fish timers count down: a fish at 0 goes back to `reset_timer` and spawns a fish at
+// `newborn_timer`. With `max_lifespan` a fish dies after that many days, the initial fish
+// counting as born on day 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PopulationModel {
+    pub reset_timer: usize,
+    pub newborn_timer: usize,
+    pub max_lifespan: Option<usize>,
+}
+
+impl PopulationModel {
+    pub const LANTERNFISH: PopulationModel = PopulationModel {
+        reset_timer: 6,
+        newborn_timer: 8,
+        max_lifespan: None,
+    };
+}
+
+impl Default for PopulationModel {
+    fn default() -> Self {
+        PopulationModel::LANTERNFISH
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PopulationOverflow {
+    pub day: usize,
+}
+
+impl fmt::Display for PopulationOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "error: the fish population no longer fits into 128 bits on day {}",
+            self.day
+        )
+    }
+}
+
+impl Error for PopulationOverflow {}
+
+// The number of states of a population: one per timer and age, where without a lifespan every
+// fish is kept at age 0. State `age * timers + timer` holds the fish of that age and timer.
+pub fn get_state_count(fishes: &[usize], model: &PopulationModel) -> (usize, usize) {
+    let timers = fishes
+        .iter()
+        .copied()
+        .chain([model.reset_timer, model.newborn_timer])
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let ages = model.max_lifespan.unwrap_or(0) + 1;
+    (timers, ages)
+}
+
+// Where the fish of every state go in one day, as (from, to) pairs; a fish at timer 0 goes to
+// two states. The day by day simulation and the matrix fast-forward both follow these.
+pub fn get_transitions(timers: usize, ages: usize, model: &PopulationModel) -> Vec<(usize, usize)> {
+    let mut transitions = vec![];
+    for age in 0..ages {
+        let next_age = match model.max_lifespan {
+            Some(lifespan) if age + 1 >= lifespan => continue,
+            Some(_) => age + 1,
+            None => 0,
+        };
+        for timer in 0..timers {
+            let from = age * timers + timer;
+            if timer == 0 {
+                transitions.push((from, next_age * timers + model.reset_timer));
+                transitions.push((from, model.newborn_timer));
+            } else {
+                transitions.push((from, next_age * timers + timer - 1));
+            }
+        }
+    }
+    transitions
+}
+
+pub fn get_initial_state(fishes: &[usize], model: &PopulationModel) -> Vec<u128> {
+    let (timers, ages) = get_state_count(fishes, model);
+    let mut counts = vec![0; timers * ages];
+    for fish in fishes {
+        counts[*fish] += 1;
+    }
+    counts
+}
+
+struct Population {
+    counts: Vec<u128>,
+    transitions: Vec<(usize, usize)>,
+}
+
+impl Population {
+    fn new(fishes: &[usize], model: &PopulationModel) -> Population {
+        let (timers, ages) = get_state_count(fishes, model);
+        Population {
+            counts: get_initial_state(fishes, model),
+            transitions: get_transitions(timers, ages, model),
+        }
+    }
+
+    fn get_total(&self) -> Option<u128> {
+        self.counts
+            .iter()
+            .try_fold(0u128, |total, count| total.checked_add(*count))
+    }
+
+    fn step(&mut self) -> Option<()> {
+        let mut next = vec![0u128; self.counts.len()];
+        for (from, to) in &self.transitions {
+            next[*to] = next[*to].checked_add(self.counts[*from])?;
+        }
+        self.counts = next;
+        Some(())
+    }
+}
+
+pub fn get_population_after_days(
+    fishes: &[usize],
+    days: usize,
+    model: &PopulationModel,
+) -> Result<u128, PopulationOverflow> {
+    let mut population = Population::new(fishes, model);
+    for day in 1..=days {
+        population.step().ok_or(PopulationOverflow { day })?;
+    }
+    population
+        .get_total()
+        .ok_or(PopulationOverflow { day: days })
+}
+
+// The population on every day from day 0 (the initial fish) to `days`, for charting.
+pub fn get_population_series(
+    fishes: &[usize],
+    days: usize,
+    model: &PopulationModel,
+) -> Result<Vec<u128>, PopulationOverflow> {
+    let mut population = Population::new(fishes, model);
+    let mut series = Vec::with_capacity(days + 1);
+    for day in 0..=days {
+        if day > 0 {
+            population.step().ok_or(PopulationOverflow { day })?;
+        }
+        series.push(population.get_total().ok_or(PopulationOverflow { day })?);
+    }
+    Ok(series)
+}
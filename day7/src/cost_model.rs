@@ -0,0 +1,58 @@
+// Fuel a crab burns to move `distance` steps. Costs have to be convex and non-decreasing in the
+// distance, so that the total over all crabs is convex in the target position.
+pub trait CostModel {
+    fn get_cost(&self, distance: i64) -> i64;
+
+    // the fastest optimiser that is exact for this model
+    fn get_optimiser(&self) -> Optimiser {
+        Optimiser::TernarySearch
+    }
+}
+
+// Part 1: one fuel per step.
+#[derive(Debug, Clone, Copy)]
+pub struct LinearCost;
+
+impl CostModel for LinearCost {
+    fn get_cost(&self, distance: i64) -> i64 {
+        distance
+    }
+
+    fn get_optimiser(&self) -> Optimiser {
+        Optimiser::Median
+    }
+}
+
+// Part 2: every step costs one more than the one before.
+#[derive(Debug, Clone, Copy)]
+pub struct TriangularCost;
+
+impl CostModel for TriangularCost {
+    fn get_cost(&self, distance: i64) -> i64 {
+        distance * (distance + 1) / 2
+    }
+
+    fn get_optimiser(&self) -> Optimiser {
+        Optimiser::AroundMean
+    }
+}
+
+// Any other convex cost, e.g. `ConvexCost(|d| d * d)`.
+#[derive(Debug, Clone, Copy)]
+pub struct ConvexCost<F>(pub F);
+
+impl<F: Fn(i64) -> i64> CostModel for ConvexCost<F> {
+    fn get_cost(&self, distance: i64) -> i64 {
+        (self.0)(distance)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Optimiser {
+    // exact for linear costs
+    Median,
+    // exact for triangular costs, whose optimum is within half a step of the mean
+    AroundMean,
+    // exact for any convex cost, O(n log range)
+    TernarySearch,
+}
@@ -0,0 +1,233 @@
+use common::{
+    lines_from_str, numbers_from_first_line, numbers_from_first_line_in_file,
+    numbers_from_first_line_in_reader, numbers_from_first_line_in_str, ParseError, Solution,
+    SolveError,
+};
+use std::{io::BufRead, path::Path};
+
+mod cost_model;
+
+pub use cost_model::{ConvexCost, CostModel, LinearCost, Optimiser, TriangularCost};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        numbers_from_first_line(&lines_from_str(input))
+    }
+
+    fn part1(positions: &Vec<i64>) -> Result<i64, SolveError> {
+        Ok(get_least_fuel_cost(positions, false))
+    }
+
+    fn part2(positions: &Vec<i64>) -> Result<i64, SolveError> {
+        Ok(get_least_fuel_cost(positions, true))
+    }
+}
+
+pub fn read_crab_horizontal_positions_from_file_by_line(
+    filename: impl AsRef<Path>,
+) -> Result<Vec<i64>, ParseError> {
+    numbers_from_first_line_in_file(filename)
+}
+
+pub fn read_crab_horizontal_positions_from_str_by_line(
+    input: &str,
+) -> Result<Vec<i64>, ParseError> {
+    numbers_from_first_line_in_str(input)
+}
+
+pub fn read_crab_horizontal_positions_from_reader_by_line(
+    reader: impl BufRead,
+) -> Result<Vec<i64>, ParseError> {
+    numbers_from_first_line_in_reader(reader)
+}
+
+// Where the crabs line up and what it costs them all together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alignment {
+    pub position: i64,
+    pub cost: i64,
+}
+
+pub fn get_total_cost(
+    horizontal_positions: &[i64],
+    target_position: i64,
+    cost_model: &(impl CostModel + ?Sized),
+) -> i64 {
+    horizontal_positions
+        .iter()
+        .map(|p| cost_model.get_cost((p - target_position).abs()))
+        .sum()
+}
+
+pub fn fuel_cost_by_target_position(horizontal_positions: &[i64], target_position: i64) -> i64 {
+    get_total_cost(horizontal_positions, target_position, &LinearCost)
+}
+
+pub fn expensive_fuel_cost_by_target_position(
+    horizontal_positions: &[i64],
+    target_position: i64,
+) -> i64 {
+    get_total_cost(horizontal_positions, target_position, &TriangularCost)
+}
+
+// None without crabs.
+pub fn get_optimal_alignment(
+    horizontal_positions: &[i64],
+    cost_model: &(impl CostModel + ?Sized),
+) -> Option<Alignment> {
+    get_optimal_alignment_with(horizontal_positions, cost_model, cost_model.get_optimiser())
+}
+
+// The lowest position with the least cost; the optimiser has to suit the cost model.
+pub fn get_optimal_alignment_with(
+    horizontal_positions: &[i64],
+    cost_model: &(impl CostModel + ?Sized),
+    optimiser: Optimiser,
+) -> Option<Alignment> {
+    let min = *horizontal_positions.iter().min()?;
+    let max = *horizontal_positions.iter().max()?;
+    let alignment_at = |position| Alignment {
+        position,
+        cost: get_total_cost(horizontal_positions, position, cost_model),
+    };
+
+    let alignment = match optimiser {
+        Optimiser::Median => {
+            let mut sorted = horizontal_positions.to_vec();
+            sorted.sort_unstable();
+            alignment_at(sorted[(sorted.len() - 1) / 2])
+        }
+        Optimiser::AroundMean => {
+            let length = horizontal_positions.len() as i64;
+            let mean = horizontal_positions.iter().sum::<i64>().div_euclid(length);
+            (mean - 1..=mean + 1)
+                .map(|position| position.clamp(min, max))
+                .map(alignment_at)
+                .min_by_key(|alignment| (alignment.cost, alignment.position))?
+        }
+        Optimiser::TernarySearch => {
+            // the total is convex, so find the first position where it stops going down
+            let (mut low, mut high) = (min, max);
+            while low < high {
+                let middle = low + (high - low) / 2;
+                if alignment_at(middle).cost <= alignment_at(middle + 1).cost {
+                    high = middle;
+                } else {
+                    low = middle + 1;
+                }
+            }
+            alignment_at(low)
+        }
+    };
+    Some(alignment)
+}
+
+pub fn get_least_fuel_cost(horizontal_positions: &[i64], expensive: bool) -> i64 {
+    let alignment = match expensive {
+        true => get_optimal_alignment(horizontal_positions, &TriangularCost),
+        false => get_optimal_alignment(horizontal_positions, &LinearCost),
+    };
+    alignment.map_or(0, |alignment| alignment.cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_all_crab_horizontal_positions_from_file_by_first_line_given_the_filename() {
+        let filename = "day7_test.txt";
+        let positions = read_crab_horizontal_positions_from_file_by_line(filename).unwrap();
+
+        assert_eq!(positions.len(), 10);
+    }
+
+    #[test]
+    fn should_get_least_fuel_cost_given_all_crab_horizontal_positions() {
+        let filename = "day7_test.txt";
+        let positions = read_crab_horizontal_positions_from_file_by_line(filename).unwrap();
+
+        let fule_cost = fuel_cost_by_target_position(&positions, 2);
+        assert_eq!(fule_cost, 37);
+
+        let fule_cost = fuel_cost_by_target_position(&positions, 1);
+        assert_eq!(fule_cost, 41);
+
+        let fule_cost = fuel_cost_by_target_position(&positions, 3);
+        assert_eq!(fule_cost, 39);
+
+        let fule_cost = fuel_cost_by_target_position(&positions, 10);
+        assert_eq!(fule_cost, 71);
+
+        let least_fuel_cost = get_least_fuel_cost(&positions, false);
+        assert_eq!(least_fuel_cost, 37);
+    }
+
+    #[test]
+    fn should_get_least_fuel_cost_given_all_crab_horizontal_positions_in_expensive_fuel_consuming_mode(
+    ) {
+        let filename = "day7_test.txt";
+        let positions = read_crab_horizontal_positions_from_file_by_line(filename).unwrap();
+
+        let fule_cost = expensive_fuel_cost_by_target_position(&positions, 2);
+        assert_eq!(fule_cost, 206);
+
+        let fule_cost = expensive_fuel_cost_by_target_position(&positions, 5);
+        assert_eq!(fule_cost, 168);
+
+        let least_fuel_cost = get_least_fuel_cost(&positions, true);
+        assert_eq!(least_fuel_cost, 168);
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_test_input_through_the_solution_trait() {
+        let input = std::fs::read_to_string("day7_test.txt").unwrap();
+        let parsed = Day7::parse(&input).unwrap();
+
+        assert_eq!(Day7::part1(&parsed).unwrap(), 37);
+        assert_eq!(Day7::part2(&parsed).unwrap(), 168);
+    }
+
+    #[test]
+    fn should_get_optimal_position_given_each_optimiser() {
+        let filename = "day7_test.txt";
+        let positions = read_crab_horizontal_positions_from_file_by_line(filename).unwrap();
+
+        let alignment = get_optimal_alignment(&positions, &LinearCost).unwrap();
+        assert_eq!((alignment.position, alignment.cost), (2, 37));
+        let alignment = get_optimal_alignment(&positions, &TriangularCost).unwrap();
+        assert_eq!((alignment.position, alignment.cost), (5, 168));
+
+        for cost_model in [&LinearCost as &dyn CostModel, &TriangularCost] {
+            assert_eq!(
+                get_optimal_alignment_with(&positions, cost_model, Optimiser::TernarySearch),
+                get_optimal_alignment(&positions, cost_model)
+            );
+        }
+
+        let squared = ConvexCost(|d: i64| d * d);
+        let alignment = get_optimal_alignment(&positions, &squared).unwrap();
+        let best = (0..=16)
+            .map(|p| get_total_cost(&positions, p, &squared))
+            .min()
+            .unwrap();
+        assert_eq!(alignment.cost, best);
+        assert_eq!(get_optimal_alignment(&[], &squared), None);
+    }
+
+    #[test]
+    fn should_get_least_fuel_cost_given_crabs_beyond_the_crab_count() {
+        let positions = vec![100, 101, 105];
+
+        let alignment = get_optimal_alignment(&positions, &LinearCost).unwrap();
+        assert_eq!((alignment.position, alignment.cost), (101, 5));
+        // at 102: 1 + 2, 1 and 1 + 2 + 3
+        assert_eq!(get_least_fuel_cost(&positions, true), 10);
+    }
+}
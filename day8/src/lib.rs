@@ -0,0 +1,293 @@
+use common::{
+    input_lines, lines_from_str, parse_lines_from_file, parse_lines_from_reader,
+    parse_lines_from_str, InputLine, ParseError, Solution, SolveError,
+};
+use std::{collections::HashSet, io::BufRead, path::Path};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = (Vec<Vec<String>>, Vec<Vec<String>>);
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<(Vec<Vec<String>>, Vec<Vec<String>>), ParseError> {
+        let lines = lines_from_str(input);
+        Ok((
+            parse_input_values_by_line(&lines)?,
+            parse_output_values_by_line(&lines)?,
+        ))
+    }
+
+    fn part1(
+        (_input_values, output_values): &(Vec<Vec<String>>, Vec<Vec<String>>),
+    ) -> Result<i64, SolveError> {
+        Ok(get_unique_number_count(output_values.clone()))
+    }
+
+    fn part2(
+        (input_values, output_values): &(Vec<Vec<String>>, Vec<Vec<String>>),
+    ) -> Result<i64, SolveError> {
+        Ok(
+            get_decode_numbers(input_values.clone(), output_values.clone())
+                .iter()
+                .sum::<i64>(),
+        )
+    }
+}
+
+fn parse_signal_patterns(
+    line: &InputLine,
+    patterns: Option<&str>,
+    count: usize,
+) -> Result<Vec<String>, ParseError> {
+    let expected = format!("{} signal patterns", count);
+    let patterns = line.expect_token(patterns, expected.as_str())?;
+    let mut values = vec![];
+    for pattern in patterns.split_whitespace() {
+        if !pattern.chars().all(|c| ('a'..='g').contains(&c)) {
+            return Err(line.error_at(pattern, "a signal pattern of letters a to g"));
+        }
+        values.push(pattern.to_string());
+    }
+    if values.len() != count {
+        return Err(line.error_at(patterns, expected));
+    }
+    Ok(values)
+}
+
+pub fn read_output_values_from_file_by_line(
+    filename: impl AsRef<Path>,
+) -> Result<Vec<Vec<String>>, ParseError> {
+    parse_lines_from_file(filename, parse_output_values_by_line)
+}
+
+pub fn read_output_values_from_str_by_line(input: &str) -> Result<Vec<Vec<String>>, ParseError> {
+    parse_lines_from_str(input, parse_output_values_by_line)
+}
+
+pub fn read_output_values_from_reader_by_line(
+    reader: impl BufRead,
+) -> Result<Vec<Vec<String>>, ParseError> {
+    parse_lines_from_reader(reader, parse_output_values_by_line)
+}
+
+pub fn parse_output_values_by_line(
+    lines_from_file: &[String],
+) -> Result<Vec<Vec<String>>, ParseError> {
+    let mut output_values = vec![];
+
+    for line in input_lines(lines_from_file) {
+        //println!("{:?}", line);
+        let mut split = line.text.trim().split('|');
+        let _ = split.next();
+        let output_value = parse_signal_patterns(&line, split.next(), 4)?;
+
+        output_values.push(output_value);
+    }
+
+    Ok(output_values)
+}
+
+pub fn read_input_values_from_file_by_line(
+    filename: impl AsRef<Path>,
+) -> Result<Vec<Vec<String>>, ParseError> {
+    parse_lines_from_file(filename, parse_input_values_by_line)
+}
+
+pub fn read_input_values_from_str_by_line(input: &str) -> Result<Vec<Vec<String>>, ParseError> {
+    parse_lines_from_str(input, parse_input_values_by_line)
+}
+
+pub fn read_input_values_from_reader_by_line(
+    reader: impl BufRead,
+) -> Result<Vec<Vec<String>>, ParseError> {
+    parse_lines_from_reader(reader, parse_input_values_by_line)
+}
+
+pub fn parse_input_values_by_line(
+    lines_from_file: &[String],
+) -> Result<Vec<Vec<String>>, ParseError> {
+    let mut input_values = vec![];
+
+    for line in input_lines(lines_from_file) {
+        //println!("{:?}", line);
+        let mut split = line.text.trim().split('|');
+        let output_value = parse_signal_patterns(&line, split.next(), 10)?;
+
+        input_values.push(output_value);
+    }
+
+    Ok(input_values)
+}
+
+pub fn get_unique_number_count(output_values: Vec<Vec<String>>) -> i64 {
+    let mut count = 0;
+    for output_value in output_values {
+        count += output_value
+            .into_iter()
+            .filter(|x| x.len() == 2 || x.len() == 3 || x.len() == 4 || x.len() == 7)
+            .count();
+    }
+    count as i64
+}
+
+pub fn generate_decode_input_set(input_line_values: Vec<String>) -> Vec<HashSet<String>> {
+    let mut decode_set = vec![HashSet::new(); 10];
+    let mut input_set = vec![];
+    for input_value in input_line_values {
+        let set: HashSet<String> = input_value.chars().map(|x| x.to_string()).collect();
+        input_set.push(set);
+    }
+
+    for input_value in input_set.iter() {
+        match input_value.len() {
+            2 => decode_set[1] = input_value.clone(),
+            3 => decode_set[7] = input_value.clone(),
+            4 => decode_set[4] = input_value.clone(),
+            7 => decode_set[8] = input_value.clone(),
+            _ => continue,
+        }
+    }
+
+    for (index, input_value) in input_set.iter().enumerate() {
+        if input_value.len() == 5 && input_value.is_superset(&decode_set[7]) {
+            decode_set[3] = input_value.clone();
+            input_set.remove(index);
+            break;
+        }
+    }
+
+    for (index, input_value) in input_set.iter().enumerate() {
+        if input_value.len() == 6 && input_value.is_superset(&decode_set[3]) {
+            decode_set[9] = input_value.clone();
+            input_set.remove(index);
+            break;
+        }
+    }
+
+    for input_value in input_set.iter() {
+        if input_value.len() == 6 {
+            if input_value.is_superset(&decode_set[7]) {
+                decode_set[0] = input_value.clone();
+            } else {
+                decode_set[6] = input_value.clone();
+            }
+        }
+
+        if input_value.len() == 5 {
+            if input_value.is_subset(&decode_set[9]) {
+                decode_set[5] = input_value.clone();
+            } else {
+                decode_set[2] = input_value.clone();
+            }
+        }
+    }
+
+    decode_set
+}
+
+pub fn get_decode_numbers(
+    input_values: Vec<Vec<String>>,
+    output_values: Vec<Vec<String>>,
+) -> Vec<i64> {
+    let mut decode_numbers = vec![];
+    for (index, output_line_values) in output_values.iter().enumerate() {
+        let mut output_predecode = vec![];
+        for output_value in output_line_values {
+            let set: HashSet<String> = output_value.chars().map(|x| x.to_string()).collect();
+            output_predecode.push(set);
+        }
+
+        //println!("current output set is {:?}", output_predecode);
+
+        let decode_input_set = generate_decode_input_set(input_values[index].clone());
+
+        //println!("current decode input set is {:?}", decode_input_set);
+
+        let mut n = 1;
+        let mut decode_output_number = 0;
+        for output_number in output_predecode.iter().rev() {
+            //println!("\ncurrent output number is {:?}", output_number);
+            for (number, decode_input_number) in decode_input_set.iter().enumerate() {
+                if output_number == decode_input_number {
+                    //println!("matched input number is {:?}\n", number);
+                    decode_output_number += number as i64 * n;
+                    n *= 10;
+                }
+            }
+        }
+        decode_numbers.push(decode_output_number);
+    }
+    decode_numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_all_output_values_from_file_by_line_given_the_filename() {
+        let filename = "day8_test.txt";
+        let output_values = read_output_values_from_file_by_line(filename).unwrap();
+
+        assert_eq!(output_values.len(), 10);
+
+        for output_value in output_values {
+            assert_eq!(output_value.len(), 4);
+        }
+    }
+
+    #[test]
+    fn should_get_all_input_values_from_file_by_line_given_the_filename() {
+        let filename = "day8_test.txt";
+        let input_values = read_input_values_from_file_by_line(filename).unwrap();
+
+        assert_eq!(input_values.len(), 10);
+
+        for input_value in input_values {
+            assert_eq!(input_value.len(), 10);
+        }
+    }
+
+    #[test]
+    fn should_get_unique_number_count_given_output_values() {
+        let filename = "day8_test.txt";
+        let output_values = read_output_values_from_file_by_line(filename).unwrap();
+
+        let unique_number_count = get_unique_number_count(output_values);
+
+        assert_eq!(unique_number_count, 26);
+    }
+
+    #[test]
+    fn should_decode_right_numbers_given_input_values_and_output_values() {
+        let filename = "day8_test.txt";
+        let input_values = read_input_values_from_file_by_line(filename).unwrap();
+        let output_values = read_output_values_from_file_by_line(filename).unwrap();
+
+        let decode_numbers = get_decode_numbers(input_values, output_values);
+
+        assert_eq!(decode_numbers[0], 8394);
+        assert_eq!(decode_numbers[1], 9781);
+        assert_eq!(decode_numbers[2], 1197);
+        assert_eq!(decode_numbers[3], 9361);
+        assert_eq!(decode_numbers[4], 4873);
+        assert_eq!(decode_numbers[5], 8418);
+        assert_eq!(decode_numbers[6], 4548);
+        assert_eq!(decode_numbers[7], 1625);
+        assert_eq!(decode_numbers[8], 8717);
+        assert_eq!(decode_numbers[9], 4315);
+
+        assert_eq!(decode_numbers.iter().sum::<i64>(), 61229);
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_test_input_through_the_solution_trait() {
+        let input = std::fs::read_to_string("day8_test.txt").unwrap();
+        let parsed = Day8::parse(&input).unwrap();
+
+        assert_eq!(Day8::part1(&parsed).unwrap(), 26);
+        assert_eq!(Day8::part2(&parsed).unwrap(), 61229);
+    }
+}
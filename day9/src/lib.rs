@@ -0,0 +1,152 @@
+use common::{
+    lines_from_str, parse_number_grid_without_split_sign_from_lines,
+    parse_number_grid_without_split_sign_from_lines_in_file,
+    parse_number_grid_without_split_sign_from_lines_in_reader,
+    parse_number_grid_without_split_sign_from_lines_in_str, Grid, ParseError, Solution, SolveError,
+};
+use std::{collections::HashSet, io::BufRead, path::Path};
+
+pub struct LowPoint {
+    pub x: i64,
+    pub y: i64,
+    pub value: i64,
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Grid<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Grid<i64>, ParseError> {
+        parse_number_grid_without_split_sign_from_lines(&lines_from_str(input))
+    }
+
+    fn part1(heightmap: &Grid<i64>) -> Result<i64, SolveError> {
+        Ok(get_low_points_from_heightmap(heightmap.clone())
+            .iter()
+            .map(|lower_point| lower_point.value + 1)
+            .sum::<i64>())
+    }
+
+    fn part2(heightmap: &Grid<i64>) -> Result<i64, SolveError> {
+        let mut basins = get_basin_sizes_from_heightmap(heightmap.clone());
+        basins.sort_unstable();
+        basins.reverse();
+        Ok(basins.iter().take(3).product::<i64>())
+    }
+}
+
+pub fn read_heightmap_from_file(filename: impl AsRef<Path>) -> Result<Grid<i64>, ParseError> {
+    parse_number_grid_without_split_sign_from_lines_in_file(filename)
+}
+
+pub fn read_heightmap_from_str(input: &str) -> Result<Grid<i64>, ParseError> {
+    parse_number_grid_without_split_sign_from_lines_in_str(input)
+}
+
+pub fn read_heightmap_from_reader(reader: impl BufRead) -> Result<Grid<i64>, ParseError> {
+    parse_number_grid_without_split_sign_from_lines_in_reader(reader)
+}
+
+pub fn get_low_points_from_heightmap(heightmap: Grid<i64>) -> Vec<LowPoint> {
+    let mut low_points = vec![];
+    for ((x, y), value) in heightmap.positions() {
+        if heightmap
+            .neighbours4(x, y)
+            .all(|neighbour| *value < heightmap[neighbour])
+        {
+            let low_point = LowPoint {
+                x: x as i64,
+                y: y as i64,
+                value: *value,
+            };
+            low_points.push(low_point);
+        }
+    }
+
+    low_points
+}
+
+pub fn get_basin_sizes_from_heightmap(heightmap: Grid<i64>) -> Vec<i64> {
+    let low_points = get_low_points_from_heightmap(heightmap.clone());
+    let mut basin_sizes = vec![];
+
+    for low_point in low_points {
+        let start = (low_point.x as usize, low_point.y as usize);
+        let mut check_list = vec![start];
+        let mut basin = HashSet::new();
+        basin.insert(start);
+        while let Some((x, y)) = check_list.pop() {
+            for neighbour in heightmap.neighbours4(x, y) {
+                if heightmap[neighbour] < 9 && basin.insert(neighbour) {
+                    check_list.push(neighbour);
+                }
+            }
+            //println!("check_list is {:?}", check_list);
+        }
+        let basin_size = basin.len() as i64;
+        //println!("basin size is {:?}", basin_size);
+        basin_sizes.push(basin_size);
+    }
+
+    basin_sizes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_heightmap_from_file_given_the_filename() {
+        let filename = "day9_test.txt";
+        let heightmap = read_heightmap_from_file(filename).unwrap();
+
+        assert_eq!(heightmap.height(), 5);
+        assert_eq!(heightmap.width(), 10);
+    }
+
+    #[test]
+    fn should_get_sum_of_risk_level_of_low_points_given_heightmap() {
+        let filename = "day9_test.txt";
+        let heightmap = read_heightmap_from_file(filename).unwrap();
+
+        let low_points = get_low_points_from_heightmap(heightmap);
+        assert_eq!(low_points.len(), 4);
+
+        let sum_risk_level = low_points
+            .iter()
+            .map(|lower_point| lower_point.value + 1)
+            .sum::<i64>();
+        assert_eq!(sum_risk_level, 15);
+    }
+
+    #[test]
+    fn should_get_right_multiply_of_3_largest_basin_sizes_given_heightmap() {
+        let filename = "day9_test.txt";
+        let heightmap = read_heightmap_from_file(filename).unwrap();
+
+        let mut basins = get_basin_sizes_from_heightmap(heightmap);
+        assert_eq!(basins.len(), 4);
+
+        basins.sort();
+        basins.reverse();
+        //println!("sorted basin sizes are {:?}", basins);
+        let large_basins = &basins[0..3];
+        assert_eq!(large_basins[0], 14);
+        assert_eq!(large_basins[1], 9);
+        assert_eq!(large_basins[2], 9);
+
+        assert_eq!(large_basins[0] * large_basins[1] * large_basins[2], 1134);
+    }
+
+    #[test]
+    fn should_get_both_answers_given_the_test_input_through_the_solution_trait() {
+        let input = std::fs::read_to_string("day9_test.txt").unwrap();
+        let parsed = Day9::parse(&input).unwrap();
+
+        assert_eq!(Day9::part1(&parsed).unwrap(), 15);
+        assert_eq!(Day9::part2(&parsed).unwrap(), 1134);
+    }
+}